@@ -5,10 +5,13 @@ use quote::format_ident;
 use syn::{
     spanned::Spanned,
     visit::{visit_type_path, Visit},
-    Attribute, Data, DeriveInput, Path, PathSegment,
+    Attribute, Data, DeriveInput, Field, Path, PathSegment,
 };
 
-use crate::{predicate::predicate_with, DebugImpl, Mode, Var};
+use crate::{
+    predicate::{predicate_resolve, predicate_with},
+    DebugImpl, Mode, Var,
+};
 
 impl TryFrom<DeriveInput> for DebugImpl {
     type Error = syn::Error;
@@ -26,18 +29,26 @@ impl TryFrom<DeriveInput> for DebugImpl {
         let Krate(krate) = args.krate.unwrap_or_default();
 
         let mut types = HashSet::new();
+        let mut resolve_types = HashSet::new();
+        let mut insert = |field: &Field| -> syn::Result<()> {
+            if FieldArgs::parse_attrs(&field.attrs)?.resolve {
+                resolve_types.insert(field.ty.clone());
+            }
+            types.insert(field.ty.clone());
+            Ok(())
+        };
 
         let mode = match data {
             Data::Struct(s) => {
                 for field in s.fields.iter() {
-                    types.insert(field.ty.clone());
+                    insert(field)?;
                 }
                 Mode::Struct(crate::StructFields(s.fields))
             }
             Data::Enum(e) => {
                 for var in e.variants.iter() {
                     for field in var.fields.iter() {
-                        types.insert(field.ty.clone());
+                        insert(field)?;
                     }
                 }
                 Mode::Enum(
@@ -54,6 +65,12 @@ impl TryFrom<DeriveInput> for DebugImpl {
         };
 
         let with_ident = format_ident!("__DebugWith");
+        predicate_resolve(
+            &mut generics,
+            krate.clone(),
+            with_ident.clone(),
+            resolve_types.into_iter(),
+        );
         predicate_with(
             &mut generics,
             krate.clone(),
@@ -113,6 +130,38 @@ impl Args {
     }
 }
 
+/// Args of `dbg_pls` on a field
+#[derive(Default)]
+pub struct FieldArgs {
+    /// Optional `resolve` arg, formatting the field with `Resolved`
+    pub resolve: bool,
+}
+
+impl FieldArgs {
+    pub fn parse_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut args = FieldArgs::default();
+
+        for attr in attrs {
+            if attr.path().is_ident(ATTR) {
+                attr.meta.require_list()?.parse_nested_meta(|meta| {
+                    match () {
+                        () if meta.path.is_ident("resolve") => {
+                            if std::mem::replace(&mut args.resolve, true) {
+                                return Err(meta.error("duplicate `dbg_pls(resolve)` arg"));
+                            }
+                        }
+                        () => return Err(meta.error("unknown argument found")),
+                    }
+
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
 struct Krate(Path);
 
 impl Default for Krate {
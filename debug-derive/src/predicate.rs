@@ -1,7 +1,8 @@
 use proc_macro2::{Ident, Span};
 use syn::{
-    punctuated::Punctuated, token, AngleBracketedGenericArguments, GenericArgument, Generics, Path,
-    PredicateType, TraitBound, Type, TypeParamBound, TypePath, WhereClause, WherePredicate,
+    parse_quote, punctuated::Punctuated, token, AngleBracketedGenericArguments, GenericArgument,
+    Generics, Path, PredicateType, TraitBound, Type, TypeParamBound, TypePath, WhereClause,
+    WherePredicate,
 };
 
 const TRAIT_WITH: &str = "DebugWith";
@@ -55,3 +56,17 @@ pub fn predicate_with(
         }))
     }
 }
+
+pub fn predicate_resolve(
+    generics: &mut Generics,
+    krate: Path,
+    with_ident: Ident,
+    types: impl Iterator<Item = Type>,
+) {
+    let wc = generics.make_where_clause();
+    for ty in types {
+        // `#with_ident: #krate::Resolve<#ty>`
+        wc.predicates
+            .push(parse_quote! { #with_ident: #krate::Resolve<#ty> });
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{DebugImpl, Var};
+use crate::{parse::FieldArgs, DebugImpl, Var};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::{spanned::Spanned, Field, Fields, Index};
+use syn::{spanned::Spanned, Field, Fields, Index, Path};
 
 impl ToTokens for DebugImpl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -22,10 +22,11 @@ impl ToTokens for DebugImpl {
 
                 let fields = match &fields.0 {
                     Fields::Named(n) => {
-                        let pat_args = n.named.iter().map(|f| f.ident.as_ref().unwrap().clone());
-                        let args = pat_args.map(|f| {
+                        let args = n.named.iter().map(|field| {
+                            let f = field.ident.as_ref().unwrap();
                             let name = f.to_string();
-                            quote! { #name, &self.#f as &dyn #krate::DebugWith<#with_ident> }
+                            let value = resolved(krate, field, quote! { &self.#f });
+                            quote! { #name, #value as &dyn #krate::DebugWith<#with_ident> }
                         });
                         quote! {
                             f.debug_struct(name) #( .field_with(#args, __with_associated_data) )* .finish()
@@ -38,10 +39,12 @@ impl ToTokens for DebugImpl {
                                 span: field.span(),
                             }
                         }
-                        let pat_args = n.unnamed.iter().enumerate().map(i);
-                        let args = pat_args.clone();
+                        let args = n.unnamed.iter().enumerate().map(|(idx, field)| {
+                            let f = i((idx, field));
+                            resolved(krate, field, quote! { &self.#f })
+                        });
                         quote! {
-                            f.debug_tuple_struct(name) #( .field_with(&self.#args as &dyn #krate::DebugWith<#with_ident>, __with_associated_data) )* .finish()
+                            f.debug_tuple_struct(name) #( .field_with(#args as &dyn #krate::DebugWith<#with_ident>, __with_associated_data) )* .finish()
                         }
                     }
                     Fields::Unit => quote! { f.debug_ident(name) },
@@ -59,9 +62,11 @@ impl ToTokens for DebugImpl {
                 match fields {
                     Fields::Named(n) => {
                         let pat_args = n.named.iter().map(|f| f.ident.as_ref().unwrap().clone());
-                        let args = pat_args.clone().map(|f| {
+                        let args = n.named.iter().map(|field| {
+                            let f = field.ident.as_ref().unwrap();
                             let name = f.to_string();
-                            quote! { #name, #f as &dyn #krate::DebugWith<#with_ident> }
+                            let value = resolved(krate, field, quote! { #f });
+                            quote! { #name, #value as &dyn #krate::DebugWith<#with_ident> }
                         });
                         quote! {
                             Self::#ident { #( ref #pat_args ),* } => f.debug_struct(#name) #( .field_with(#args, __with_associated_data) )* .finish(),
@@ -72,7 +77,10 @@ impl ToTokens for DebugImpl {
                             format_ident!("__self_{}", i, span = field.span())
                         }
                         let pat_args = n.unnamed.iter().enumerate().map(i);
-                        let args = pat_args.clone();
+                        let args = n.unnamed.iter().enumerate().map(|(idx, field)| {
+                            let f = i((idx, field));
+                            resolved(krate, field, quote! { #f })
+                        });
                         quote! {
                             Self::#ident ( #( ref #pat_args ),* ) => f.debug_tuple_struct(#name) #( .field_with(#args as &dyn #krate::DebugWith<#with_ident>, __with_associated_data) )* .finish(),
                         }
@@ -95,3 +103,13 @@ impl ToTokens for DebugImpl {
         })
     }
 }
+
+/// Wraps the field reference in `Resolved` if it has the `#[dbg_pls(resolve)]` attribute.
+/// Invalid attributes have already been reported while parsing
+fn resolved(krate: &Path, field: &Field, value: TokenStream) -> TokenStream {
+    if FieldArgs::parse_attrs(&field.attrs).is_ok_and(|args| args.resolve) {
+        quote! { &#krate::Resolved(#value) }
+    } else {
+        value
+    }
+}
@@ -1,86 +1,186 @@
-use std::sync::OnceLock;
+use std::{borrow::Cow, sync::OnceLock};
 
-use stylish::{Ansi, Foreground, Style, Write};
-use syntect::parsing::{
-    BasicScopeStackOp, ParseScopeError, Scope, ScopeStack, ScopeStackOp, SyntaxDefinition,
-    SyntaxReference, SyntaxSet, SyntaxSetBuilder,
-};
+use stylish::{Ansi, Color as AnsiColor, Foreground, Style, Write};
 
-use crate::{pretty::pretty_string, DebugPls, DebugWith, Formatter};
-
-fn syntax() -> &'static SyntaxSet {
-    static INSTANCE: OnceLock<SyntaxSet> = OnceLock::new();
-    INSTANCE.get_or_init(|| {
-        let mut syntax_set = SyntaxSetBuilder::new();
-        syntax_set.add(
-            SyntaxDefinition::load_from_str(
-                include_str!("../assets/syntaxes/Rust/Rust.sublime-syntax"),
-                false,
-                None,
-            )
-            .unwrap(),
-        );
-        syntax_set.build()
-    })
-}
+mod ansi;
+mod html;
+mod theme;
+pub use ansi::ColorDepth;
+pub use html::{color_html, write_html, HtmlStyle};
+pub use theme::Theme;
+
+use crate::{
+    config,
+    pretty::{diff, pretty_highlighted, source_highlighted, AssertFailed, AssertKind, Highlighted},
+    Config, DebugPls, DebugWith,
+};
 
 fn theme() -> &'static Theme {
     static INSTANCE: OnceLock<Theme> = OnceLock::new();
-    INSTANCE.get_or_init(|| get_theme().unwrap())
+    INSTANCE.get_or_init(|| Theme::from_env().unwrap_or_default())
 }
 
-fn highlight(s: &str, w: impl std::fmt::Write) -> std::fmt::Result {
-    let syntax = syntax();
-    let rust = syntax.find_syntax_by_name("Rust").unwrap();
-    let theme = theme();
-
-    let parsed = RustSyntax { syntax, rust }.parse_shell(s);
+fn highlight(h: &Highlighted, theme: &Theme, w: impl std::fmt::Write) -> std::fmt::Result {
+    theme.highlight(h, ansi::Ansi::new(w, theme.depth()))
+}
 
-    theme.highlight(s, &parsed, Ansi::new(w))
+/// Highlights the text, unless the config has colors disabled
+fn write(
+    h: &Highlighted,
+    config: &Config,
+    theme: &Theme,
+    mut w: impl std::fmt::Write,
+) -> std::fmt::Result {
+    if config.use_color() {
+        highlight(h, theme, w)
+    } else {
+        w.write_str(&h.text)
+    }
 }
 
 /// Implementation detail for the `color!` macro
-pub struct ColorStr<'a>(pub &'a str);
+pub struct ColorStr<'a>(pub &'a Config, pub &'a str);
 
-impl<'a> std::fmt::Display for ColorStr<'a> {
+impl std::fmt::Display for ColorStr<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let expr = &syn::parse_str(self.0).map_err(|_| std::fmt::Error)?;
-        highlight(&pretty_string(expr), f)
+        let h = source_highlighted(self.1, self.0.max_width());
+        write(&h, self.0, theme(), f)
     }
 }
 
-struct Color<'a>(&'a dyn DebugPls);
+struct Color<'a> {
+    value: &'a dyn DebugPls,
+    config: Option<&'a Config>,
+    theme: Option<&'a Theme>,
+}
 
-impl<'a> std::fmt::Debug for Color<'a> {
+impl std::fmt::Debug for Color<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        highlight(&pretty_string(&Formatter::process(self.0)), f)
+        let config = self
+            .config
+            .map_or_else(|| Cow::Owned(Config::global()), Cow::Borrowed);
+        let expr = config.process(self.value);
+        write(
+            &pretty_highlighted(&expr, config.max_width()),
+            &config,
+            self.theme.unwrap_or(theme()),
+            f,
+        )
     }
 }
 
-impl<'a> std::fmt::Display for Color<'a> {
+impl std::fmt::Display for Color<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Debug::fmt(self, f)
     }
 }
 
-struct ColorWith<'a, T>(&'a T, &'a dyn DebugWith<T>);
+struct ColorWith<'a, T> {
+    with: &'a T,
+    value: &'a dyn DebugWith<T>,
+    config: Option<&'a Config>,
+}
 
-impl<'a, T> std::fmt::Debug for ColorWith<'a, T> {
+impl<T> std::fmt::Debug for ColorWith<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        highlight(&pretty_string(&Formatter::process_with(self.1, self.0)), f)
+        let config = self
+            .config
+            .map_or_else(|| Cow::Owned(Config::global()), Cow::Borrowed);
+        let expr = config.process_with(self.value, self.with);
+        let h = pretty_highlighted(&expr, config.max_width());
+        write(&h, &config, theme(), f)
     }
 }
 
-impl<'a, T> std::fmt::Display for ColorWith<'a, T> {
+impl<T> std::fmt::Display for ColorWith<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Debug::fmt(self, f)
     }
 }
 
+struct ColorAssertFailed<'a>(AssertFailed<'a>);
+
+impl std::fmt::Display for ColorAssertFailed<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let failed = &self.0;
+        failed.header(f)?;
+        match failed.kind {
+            AssertKind::Eq => {
+                f.write_str("diff (- left / + right):\n")?;
+                let removed = Style::default().with(Foreground(AnsiColor::Red));
+                let added = Style::default().with(Foreground(AnsiColor::Green));
+                let mut w = Ansi::new(&mut *f);
+                for line in diff::lines(&failed.left.text, &failed.right.text) {
+                    let (prefix, line, style) = match line {
+                        diff::Line::Both(line) => (" ", line, Style::default()),
+                        diff::Line::Left(line) => ("-", line, removed),
+                        diff::Line::Right(line) => ("+", line, added),
+                    };
+                    w.write_str(prefix, style)?;
+                    w.write_str(line, style)?;
+                    w.write_str("\n", style)?;
+                }
+                w.finish()?;
+                Ok(())
+            }
+            AssertKind::Ne => {
+                f.write_str("  left: ")?;
+                highlight(&failed.left, theme(), &mut *f)?;
+                f.write_str("\n right: ")?;
+                highlight(&failed.right, theme(), f)
+            }
+        }
+    }
+}
+
+/// Implementation detail for the `assert_eq_color!` and `assert_ne_color!` macros
+///
+/// # Panics
+///
+/// Always panics, describing the failed assertion
+#[track_caller]
+pub fn assert_failed(
+    kind: AssertKind,
+    left: &dyn DebugPls,
+    right: &dyn DebugPls,
+    args: Option<std::fmt::Arguments<'_>>,
+) -> ! {
+    let failed = AssertFailed::new(kind, left, right, args);
+    if config::assert_color().enabled(&std::io::stderr()) {
+        panic!("{}", ColorAssertFailed(failed))
+    } else {
+        panic!("{failed}")
+    }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
 /// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`]
 pub fn color(value: &impl DebugPls) -> impl std::fmt::Debug + std::fmt::Display + '_ {
-    Color(value)
+    Color {
+        value,
+        config: None,
+        theme: None,
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+/// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+/// highlighted using the given [`Theme`]
+///
+/// ```rust
+/// use dbg_pls::{color_themed, Theme};
+///
+/// println!("{}", color_themed(&Some("hello"), &Theme::solarized_light()));
+/// ```
+pub fn color_themed<'a>(
+    value: &'a impl DebugPls,
+    theme: &'a Theme,
+) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+    Color {
+        value,
+        config: None,
+        theme: Some(theme),
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
@@ -89,7 +189,42 @@ pub fn color_with<'a, T>(
     with: &'a T,
     value: &'a impl DebugWith<T>,
 ) -> impl std::fmt::Debug + std::fmt::Display + 'a {
-    ColorWith(with, value)
+    ColorWith {
+        with,
+        value,
+        config: None,
+    }
+}
+
+impl Config {
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    /// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+    /// formatted with this config
+    pub fn color<'a>(
+        &'a self,
+        value: &'a impl DebugPls,
+    ) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+        Color {
+            value,
+            config: Some(self),
+            theme: None,
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    /// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+    /// formatted with this config
+    pub fn color_with<'a, T>(
+        &'a self,
+        with: &'a T,
+        value: &'a impl DebugWith<T>,
+    ) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+        ColorWith {
+            with,
+            value,
+            config: Some(self),
+        }
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
@@ -111,7 +246,7 @@ pub fn color_with<'a, T>(
 /// The macro works by using the [`DebugPls`] implementation of the type of
 /// the given expression to print the value to [stderr] along with the
 /// source location of the macro invocation as well as the source code
-/// of the expression.
+/// of the expression. The output can be redirected using [`set_output`].
 ///
 /// Invoking the macro on an expression moves and takes ownership of it
 /// before returning the evaluated expression unchanged. If the type
@@ -122,6 +257,8 @@ pub fn color_with<'a, T>(
 /// The `color!` macro works exactly the same in release builds.
 /// This is useful when debugging issues that only occur in release
 /// builds or when debugging in release mode is significantly faster.
+/// Enabling the `strip-in-release` feature instead makes the macro evaluate to just
+/// the expression when `debug_assertions` are disabled.
 ///
 /// Note that the macro is intended as a debugging tool and therefore you
 /// should avoid having uses of it in version control for long periods
@@ -130,25 +267,45 @@ pub fn color_with<'a, T>(
 /// such as the [`debug!`] macro from the [`log`] crate.
 ///
 /// [stderr]: https://en.wikipedia.org/wiki/Standard_streams#Standard_error_(stderr)
+/// [`set_output`]: crate::set_output
 /// [`debug!`]: https://docs.rs/log/*/log/macro.debug.html
 /// [`log`]: https://crates.io/crates/log
 macro_rules! color {
     () => {
-        ::std::eprintln!("[{}:{}]", ::std::file!(), ::std::line!())
+        $crate::__strip_in_release!(
+            $crate::__private::print(::std::format_args!(
+                "{}",
+                $crate::__private::Location {
+                    config: &$crate::__private::macro_config(),
+                    file: ::std::file!(),
+                    line: ::std::line!(),
+                    column: ::std::column!(),
+                }
+            )),
+            ()
+        )
     };
     ($val:expr $(,)?) => {
-        match $val {
-            tmp => {
-                ::std::eprintln!(
-                    "[{}:{}] {} => {}",
-                    ::std::file!(),
-                    ::std::line!(),
-                    $crate::__private::ColorStr(::std::stringify!($val)),
-                    $crate::color(&tmp)
-                );
-                tmp
-            }
-        }
+        $crate::__strip_in_release!(
+            match $val {
+                tmp => {
+                    let config = $crate::__private::macro_config();
+                    $crate::__private::print(::std::format_args!(
+                        "{} {} => {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::ColorStr(&config, ::std::stringify!($val)),
+                        config.color(&tmp)
+                    ));
+                    tmp
+                }
+            },
+            $val
+        )
     };
     ($($val:expr),+ $(,)?) => {
         ($($crate::color!($val)),+,)
@@ -174,7 +331,7 @@ macro_rules! color {
 /// The macro works by using the [`DebugPls`] implementation of the type of
 /// the given expression to print the value to [stderr] along with the
 /// source location of the macro invocation as well as the source code
-/// of the expression.
+/// of the expression. The output can be redirected using [`set_output`].
 ///
 /// Invoking the macro on an expression moves and takes ownership of it
 /// before returning the evaluated expression unchanged. If the type
@@ -185,6 +342,8 @@ macro_rules! color {
 /// The `color!` macro works exactly the same in release builds.
 /// This is useful when debugging issues that only occur in release
 /// builds or when debugging in release mode is significantly faster.
+/// Enabling the `strip-in-release` feature instead makes the macro evaluate to just
+/// the expression when `debug_assertions` are disabled.
 ///
 /// Note that the macro is intended as a debugging tool and therefore you
 /// should avoid having uses of it in version control for long periods
@@ -193,36 +352,155 @@ macro_rules! color {
 /// such as the [`debug!`] macro from the [`log`] crate.
 ///
 /// [stderr]: https://en.wikipedia.org/wiki/Standard_streams#Standard_error_(stderr)
+/// [`set_output`]: crate::set_output
 /// [`debug!`]: https://docs.rs/log/*/log/macro.debug.html
 /// [`log`]: https://crates.io/crates/log
 macro_rules! color_with {
     () => {
-        ::std::eprintln!("[{}:{}]", ::std::file!(), ::std::line!())
+        $crate::__strip_in_release!(
+            $crate::__private::print(::std::format_args!(
+                "{}",
+                $crate::__private::Location {
+                    config: &$crate::__private::macro_config(),
+                    file: ::std::file!(),
+                    line: ::std::line!(),
+                    column: ::std::column!(),
+                }
+            )),
+            ()
+        )
     };
     ($with:expr, $val:expr $(,)?) => {
-        match $val {
-            tmp => {
-                ::std::eprintln!(
-                    "[{}:{}] {} => {}",
-                    ::std::file!(),
-                    ::std::line!(),
-                    $crate::__private::ColorStr(::std::stringify!($val)),
-                    $crate::color_with($with, &tmp)
-                );
-                tmp
-            }
-        }
+        $crate::__strip_in_release!(
+            match $val {
+                tmp => {
+                    let config = $crate::__private::macro_config();
+                    $crate::__private::print(::std::format_args!(
+                        "{} {} => {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::ColorStr(&config, ::std::stringify!($val)),
+                        config.color_with($with, &tmp)
+                    ));
+                    tmp
+                }
+            },
+            $val
+        )
     };
     ($with:expr, $($val:expr),+ $(,)?) => {
         ($($crate::color_with!($with, $val)),+,)
     };
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+#[macro_export]
+/// Asserts that two expressions are equal to each other (using [`PartialEq`]).
+/// Same as [`std::assert_eq`]
+///
+/// On panic, this macro will print a colored line diff of the values of the expressions,
+/// pretty printed using their [`DebugPls`] implementations.
+/// Like the `color!` macros, colors are left out when stderr is not a terminal,
+/// see [`ColorChoice`](crate::ColorChoice).
+///
+/// ```rust
+/// # use dbg_pls::assert_eq_color;
+/// assert_eq_color!(vec![1 + 1, 2], vec![2, 2]);
+/// ```
+///
+/// [`DebugPls`]: crate::DebugPls
+macro_rules! assert_eq_color {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::assert_failed_color(
+                        $crate::__private::AssertKind::Eq,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::assert_failed_color(
+                        $crate::__private::AssertKind::Eq,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::Some(::std::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+#[macro_export]
+/// Asserts that two expressions are not equal to each other (using [`PartialEq`]).
+/// Same as [`std::assert_ne`]
+///
+/// On panic, this macro will print the syntax highlighted values of the expressions,
+/// pretty printed using their [`DebugPls`] implementations.
+/// Like the `color!` macros, colors are left out when stderr is not a terminal,
+/// see [`ColorChoice`](crate::ColorChoice).
+///
+/// ```rust
+/// # use dbg_pls::assert_ne_color;
+/// assert_ne_color!(vec![1, 2], vec![2, 1], "order matters");
+/// ```
+///
+/// [`DebugPls`]: crate::DebugPls
+macro_rules! assert_ne_color {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if *left_val == *right_val {
+                    $crate::__private::assert_failed_color(
+                        $crate::__private::AssertKind::Ne,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if *left_val == *right_val {
+                    $crate::__private::assert_failed_color(
+                        $crate::__private::AssertKind::Ne,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::Some(::std::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
-    use crate::color;
+    use stylish::{Color, Foreground, Style};
+
+    use super::{config, AssertFailed, AssertKind, ColorAssertFailed};
+    use crate::{
+        color_themed, pretty, ColorChoice, ColorDepth, Config, DebugPls, Formatter, Theme,
+    };
 
     #[test]
     fn colors() {
@@ -235,127 +513,113 @@ mod tests {
         // map is moved through properly
         assert_eq!(map, HashMap::from([("hello", 1), ("world", 2),]));
     }
-}
 
-impl Theme {
-    // this is a manual/simpler implementation of
-    // syntect::highlight::HighlightIterator
-    // to use a custom theme using `ratatui::Style`.
-    // This is so we don't have to care about RGB and can instead use
-    // terminal colours
-    fn highlight(&self, h: &str, parsed: &ParsedSyntax, mut w: impl Write) -> std::fmt::Result {
-        let mut stack = ScopeStack::default();
-        let mut styles: Vec<(f64, Style)> = vec![];
-        for (line, parsed_line) in h.lines().zip(parsed) {
-            let mut last = 0;
-            for &(index, ref op) in parsed_line {
-                let style = styles.last().copied().unwrap_or_default().1;
-                stack
-                    .apply_with_hook(op, |op, stack| {
-                        highlight_hook(&op, stack, &self.rules, &mut styles);
-                    })
-                    .unwrap();
-
-                w.write_str(&line[last..index], style)?;
-                last = index;
-            }
-            let style = styles.last().copied().unwrap_or_default().1;
-            w.write_str(&line[last..], style)?;
-            w.write_str("\n", style)?;
-        }
-        Ok(())
+    #[test]
+    fn color_never() {
+        let value = ("hello", [1, 2]);
+        let config = Config::new().color_choice(ColorChoice::Never);
+        assert_eq!(config.color(&value).to_string(), pretty(&value).to_string());
     }
-}
 
-#[allow(clippy::cast_possible_truncation)]
-fn highlight_hook(
-    op: &BasicScopeStackOp,
-    stack: &[Scope],
-    rules: &[ThemeRule],
-    styles: &mut Vec<(f64, Style)>,
-) {
-    match op {
-        BasicScopeStackOp::Push(scope) => {
-            let mut scored_style = styles
-                .last()
-                .copied()
-                .unwrap_or_else(|| (-1.0, Style::default()));
-
-            for rule in rules.iter().filter(|a| a.scope.is_prefix_of(*scope)) {
-                let single_score =
-                    f64::from(rule.scope.len()) * f64::from(3 * ((stack.len() - 1) as u32)).exp2();
-
-                if single_score > scored_style.0 {
-                    scored_style.0 = single_score;
-                    scored_style.1 = rule.style;
-                }
-            }
+    #[test]
+    fn assert_ne_color_message() {
+        let failed = AssertFailed::new(AssertKind::Ne, &[1], &[1], None);
+        assert_eq!(
+            ColorAssertFailed(failed).to_string(),
+            "assertion `left != right` failed\n  \
+            left: \x1b[31m[\x1b[35m1\x1b[31m]\x1b[0m\n \
+            right: \x1b[31m[\x1b[35m1\x1b[31m]\x1b[0m",
+        );
+    }
 
-            styles.push(scored_style);
-        }
-        BasicScopeStackOp::Pop => {
-            styles.pop();
-        }
+    #[test]
+    fn assert_color_auto() {
+        // panic messages are only colored when stderr is a terminal
+        let path = std::env::temp_dir().join("dbg_pls_assert_color");
+        let file = std::fs::File::create(path).unwrap();
+        assert_eq!(config::assert_color(), ColorChoice::Auto);
+        assert!(!config::assert_color().enabled(&file));
+        assert!(ColorChoice::Always.enabled(&file));
     }
-}
 
-#[derive(Clone, Copy)]
-struct RustSyntax<'s> {
-    syntax: &'s SyntaxSet,
-    rust: &'s SyntaxReference,
-}
-type ParsedSyntax = Vec<Vec<(usize, ScopeStackOp)>>;
-
-impl RustSyntax<'_> {
-    fn parse_shell(self, h: &str) -> ParsedSyntax {
-        let mut rust = syntect::parsing::ParseState::new(self.rust);
-
-        let mut lines = vec![];
-        for line in h.lines() {
-            if let Ok(line) = rust.parse_line(line, self.syntax) {
-                lines.push(line);
-            } else {
-                lines.push(Vec::new());
+    #[test]
+    fn tags() {
+        struct Lowercase;
+        impl DebugPls for Lowercase {
+            fn fmt(&self, f: Formatter<'_>) {
+                f.debug_ident("lowercase");
             }
         }
-        lines
+
+        let theme = Theme::new()
+            .rule(
+                "entity.name.variant",
+                Style::default().with(Foreground(Color::Green)),
+            )
+            .rule(
+                "meta.mapping.key",
+                Style::default().with(Foreground(Color::Blue)),
+            );
+
+        // the variant is tagged by `debug_ident`, not recognised by its capital letter
+        let ansi = color_themed(&Lowercase, &theme).to_string();
+        assert_eq!(ansi, "\x1b[32mlowercase\x1b[0m");
+
+        let ansi = color_themed(&BTreeMap::from([("a", 1)]), &theme).to_string();
+        assert_eq!(ansi, "{\n    [\x1b[34m\"a\"\x1b[0m] = 1;\n}");
     }
-}
 
-struct Theme {
-    rules: Vec<ThemeRule>,
-}
+    #[test]
+    fn override_bundled_rule() {
+        let theme =
+            Theme::default().rule("string", Style::default().with(Foreground(Color::Green)));
 
-struct ThemeRule {
-    scope: Scope,
-    style: Style,
-}
+        let ansi = color_themed(&"hello", &theme).to_string();
+        assert_eq!(ansi, "\x1b[32m\"hello\"\x1b[0m");
+    }
 
-// blame syntax highlighting
-#[allow(clippy::too_many_lines)]
-fn get_theme() -> Result<Theme, ParseScopeError> {
-    use stylish::Color;
-    let rules = vec![
-        ThemeRule {
-            scope: Scope::new("variable")?,
-            style: Style::default().with(Foreground(Color::Blue)),
-        },
-        ThemeRule {
-            scope: Scope::new("keyword")?,
-            style: Style::default().with(Foreground(Color::Red)),
-        },
-        ThemeRule {
-            scope: Scope::new("punctuation")?,
-            style: Style::default().with(Foreground(Color::Red)),
-        },
-        ThemeRule {
-            scope: Scope::new("storage")?,
-            style: Style::default().with(Foreground(Color::Green)),
-        },
-        ThemeRule {
-            scope: Scope::new("string")?,
-            style: Style::default().with(Foreground(Color::Yellow)),
-        },
-    ];
-    Ok(Theme { rules })
+    #[test]
+    fn tmtheme() {
+        let tmtheme = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>Test</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#F8F8F2</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>string</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#E6DB74</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>"#;
+        let theme = Theme::from_tmtheme(std::io::Cursor::new(tmtheme))
+            .unwrap()
+            .color_depth(ColorDepth::Ansi16);
+
+        let ansi = color_themed(&"hello", &theme).to_string();
+        assert!(ansi.contains("\x1b[37m\"hello"), "{ansi:?}");
+
+        let theme = theme.color_depth(ColorDepth::Ansi256);
+        let ansi = color_themed(&"hello", &theme).to_string();
+        assert!(ansi.contains("\x1b[38;5;186m\"hello"), "{ansi:?}");
+
+        let theme = theme.color_depth(ColorDepth::TrueColor);
+        let ansi = color_themed(&"hello", &theme).to_string();
+        assert!(ansi.contains("\x1b[38;2;230;219;116m\"hello"), "{ansi:?}");
+    }
 }
@@ -0,0 +1,274 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use stylish::{Color, Intensity, Style};
+
+/// The range of colors that a terminal supports
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// The 16 standard ANSI colors. RGB colors are approximated with the nearest of these
+    #[default]
+    Ansi16,
+    /// The 256 color xterm palette. RGB colors are approximated with the nearest of these
+    Ansi256,
+    /// 24-bit truecolor. RGB colors are used as is
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth supported by the terminal from the environment.
+    ///
+    /// `COLORTERM=truecolor` or `COLORTERM=24bit` selects [`ColorDepth::TrueColor`],
+    /// a `TERM` such as `xterm-256color` selects [`ColorDepth::Ansi256`],
+    /// and anything else falls back to [`ColorDepth::Ansi16`].
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            ColorDepth::TrueColor
+        } else if term.is_some_and(|term| term.contains("256color")) {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+/// The style of a span of highlighted text.
+///
+/// `rgb` is the exact color requested by the theme, if any, which is used
+/// in place of the foreground of `style` when the color depth allows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct Paint {
+    pub(super) style: Style,
+    pub(super) rgb: Option<[u8; 3]>,
+}
+
+/// A writer of highlighted text
+pub(super) trait PaintWrite {
+    fn write_str(&mut self, s: &str, paint: Paint) -> fmt::Result;
+
+    /// Resets the style at the end of the text
+    fn finish(&mut self) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// Like [`stylish::Ansi`], but with support for 256 color and truecolor foregrounds
+pub(super) struct Ansi<W> {
+    inner: W,
+    depth: ColorDepth,
+    current: Paint,
+}
+
+impl<W: Write> Ansi<W> {
+    pub(super) fn new(inner: W, depth: ColorDepth) -> Self {
+        Self {
+            inner,
+            depth,
+            current: Paint::default(),
+        }
+    }
+
+    fn foreground(&self, paint: Paint) -> Cow<'static, str> {
+        match (paint.rgb, self.depth) {
+            (Some([r, g, b]), ColorDepth::TrueColor) => format!("38;2;{r};{g};{b}").into(),
+            (Some(rgb), ColorDepth::Ansi256) => format!("38;5;{}", nearest_256(rgb)).into(),
+            _ => foreground(paint.style.foreground).into(),
+        }
+    }
+}
+
+impl<W: Write> PaintWrite for Ansi<W> {
+    fn write_str(&mut self, s: &str, paint: Paint) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        if self.current != paint && paint == Paint::default() {
+            self.inner.write_str("\x1b[0m")?;
+        } else {
+            let (old, new) = (self.current.style, paint.style);
+            let fg = self.foreground(paint);
+            let segments = [
+                (fg != self.foreground(self.current)).then_some(fg),
+                (new.background != old.background).then(|| background(new.background).into()),
+                (new.intensity != old.intensity).then(|| intensity(new.intensity).into()),
+            ];
+            let mut segments = segments.into_iter().flatten();
+            if let Some(segment) = segments.next() {
+                self.inner.write_str("\x1b[")?;
+                self.inner.write_str(&segment)?;
+                for segment in segments {
+                    self.inner.write_str(";")?;
+                    self.inner.write_str(&segment)?;
+                }
+                self.inner.write_str("m")?;
+            }
+        }
+        self.current = paint;
+
+        self.inner.write_str(s)
+    }
+
+    fn finish(&mut self) -> fmt::Result {
+        if self.current != Paint::default() {
+            self.inner.write_str("\x1b[0m")?;
+            self.current = Paint::default();
+        }
+        Ok(())
+    }
+}
+
+fn foreground(color: Color) -> &'static str {
+    match color {
+        Color::Black => "30",
+        Color::Red => "31",
+        Color::Green => "32",
+        Color::Yellow => "33",
+        Color::Blue => "34",
+        Color::Magenta => "35",
+        Color::Cyan => "36",
+        Color::White => "37",
+        Color::BrightBlack => "90",
+        Color::BrightRed => "91",
+        Color::BrightGreen => "92",
+        Color::BrightYellow => "93",
+        Color::BrightBlue => "94",
+        Color::BrightMagenta => "95",
+        Color::BrightCyan => "96",
+        Color::BrightWhite => "97",
+        _ => "39",
+    }
+}
+
+fn background(color: Color) -> &'static str {
+    match color {
+        Color::Black => "40",
+        Color::Red => "41",
+        Color::Green => "42",
+        Color::Yellow => "43",
+        Color::Blue => "44",
+        Color::Magenta => "45",
+        Color::Cyan => "46",
+        Color::White => "47",
+        Color::BrightBlack => "100",
+        Color::BrightRed => "101",
+        Color::BrightGreen => "102",
+        Color::BrightYellow => "103",
+        Color::BrightBlue => "104",
+        Color::BrightMagenta => "105",
+        Color::BrightCyan => "106",
+        Color::BrightWhite => "107",
+        _ => "49",
+    }
+}
+
+fn intensity(intensity: Intensity) -> &'static str {
+    match intensity {
+        Intensity::Bold => "1",
+        Intensity::Faint => "2",
+        _ => "22",
+    }
+}
+
+/// The xterm defaults for the 16 standard colors
+const ANSI_16: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [205, 0, 0]),
+    (Color::Green, [0, 205, 0]),
+    (Color::Yellow, [205, 205, 0]),
+    (Color::Blue, [0, 0, 238]),
+    (Color::Magenta, [205, 0, 205]),
+    (Color::Cyan, [0, 205, 205]),
+    (Color::White, [229, 229, 229]),
+    (Color::BrightBlack, [127, 127, 127]),
+    (Color::BrightRed, [255, 0, 0]),
+    (Color::BrightGreen, [0, 255, 0]),
+    (Color::BrightYellow, [255, 255, 0]),
+    (Color::BrightBlue, [92, 92, 255]),
+    (Color::BrightMagenta, [255, 0, 255]),
+    (Color::BrightCyan, [0, 255, 255]),
+    (Color::BrightWhite, [255, 255, 255]),
+];
+
+/// The channel values of the 6x6x6 color cube in the 256 color palette
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(&a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
+
+/// The RGB value of one of the 16 standard colors, or `None` for the default color
+pub(super) fn rgb(color: Color) -> Option<[u8; 3]> {
+    ANSI_16
+        .iter()
+        .find(|(c, _)| *c == color)
+        .map(|&(_, rgb)| rgb)
+}
+
+/// Finds the closest of the 16 standard colors
+pub(super) fn nearest_16(rgb: [u8; 3]) -> Color {
+    ANSI_16
+        .iter()
+        .min_by_key(|(_, c)| distance(rgb, *c))
+        .map_or(Color::Default, |&(color, _)| color)
+}
+
+/// Finds the closest color in the 256 color palette, out of the color cube and the grayscale ramp
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn nearest_256(rgb: [u8; 3]) -> u8 {
+    let cube_index = |c: u8| {
+        (0..6)
+            .min_by_key(|&i| CUBE[i].abs_diff(c))
+            .unwrap_or_default()
+    };
+    let [r, g, b] = rgb.map(cube_index);
+    let cube = [CUBE[r], CUBE[g], CUBE[b]];
+
+    // the grayscale ramp covers 8, 18, ..., 238
+    let average = rgb.iter().map(|&c| u32::from(c)).sum::<u32>() / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let level = 8 + 10 * gray_index;
+
+    if distance(rgb, [level; 3]) < distance(rgb, cube) {
+        232 + gray_index
+    } else {
+        (16 + 36 * r + 6 * g + b) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stylish::Color;
+
+    use super::{nearest_16, nearest_256, ColorDepth};
+
+    #[test]
+    fn detect_depth() {
+        let detect = ColorDepth::from_env;
+        assert_eq!(detect(Some("truecolor"), None), ColorDepth::TrueColor);
+        assert_eq!(detect(Some("24bit"), Some("xterm")), ColorDepth::TrueColor);
+        assert_eq!(detect(None, Some("xterm-256color")), ColorDepth::Ansi256);
+        assert_eq!(detect(None, Some("xterm")), ColorDepth::Ansi16);
+        assert_eq!(detect(None, None), ColorDepth::Ansi16);
+    }
+
+    #[test]
+    fn nearest_colors() {
+        assert_eq!(nearest_16([249, 38, 114]), Color::Magenta);
+        assert_eq!(nearest_16([39, 40, 34]), Color::Black);
+        assert_eq!(nearest_256([249, 38, 114]), 197);
+        assert_eq!(nearest_256([39, 40, 34]), 235);
+        assert_eq!(nearest_256([255, 255, 255]), 231);
+    }
+}
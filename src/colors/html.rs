@@ -0,0 +1,156 @@
+use std::fmt::{self, Write};
+
+use stylish::Intensity;
+
+use super::ansi::{self, Paint, PaintWrite};
+use super::Theme;
+use crate::{
+    pretty::{pretty_highlighted, Highlighted},
+    Config, DebugPls,
+};
+
+/// How [`write_html`] styles the highlighted text
+#[derive(Clone, Copy, Debug, Default)]
+pub enum HtmlStyle<'a> {
+    /// Wraps each token in a `<span>`, with a class for each part of its scope name.
+    ///
+    /// For example, a string has the `string.quoted` scope and is rendered as
+    /// `<span class="string quoted">`, ready to be styled with CSS.
+    #[default]
+    Classes,
+    /// Styles each span of text inline, using the colors of the given [`Theme`]
+    Inline(&'a Theme),
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+/// Wraps a [`DebugPls`] type into a [`std::fmt::Display`] type that renders
+/// syntax highlighted HTML, using [`HtmlStyle::Classes`]
+///
+/// ```rust
+/// use dbg_pls::color_html;
+///
+/// let html = color_html(&Some("<b>")).to_string();
+/// assert!(html.starts_with("<pre class=\"dbg-pls\">"));
+/// assert!(html.contains("&lt;b&gt;"));
+/// ```
+pub fn color_html(value: &impl DebugPls) -> impl fmt::Display + '_ {
+    struct ColorHtml<'a>(&'a dyn DebugPls);
+
+    impl fmt::Display for ColorHtml<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_html(f, self.0, HtmlStyle::Classes)
+        }
+    }
+
+    ColorHtml(value)
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+/// Writes the syntax highlighted HTML rendering of a [`DebugPls`] type,
+/// wrapped in a `<pre class="dbg-pls">` element.
+///
+/// # Errors
+/// This will return an error if writing to `w` fails
+///
+/// ```rust
+/// use dbg_pls::{write_html, HtmlStyle, Theme};
+///
+/// let mut html = String::new();
+/// write_html(&mut html, &("hello", 42), HtmlStyle::Inline(&Theme::monokai()))?;
+/// assert!(html.contains("<span style=\"color:#e6db74\">"));
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+pub fn write_html(mut w: impl Write, value: &dyn DebugPls, style: HtmlStyle<'_>) -> fmt::Result {
+    let config = Config::global();
+    let h = pretty_highlighted(&config.process(value), config.max_width());
+
+    w.write_str("<pre class=\"dbg-pls\">")?;
+    match style {
+        HtmlStyle::Classes => classes(&h, &mut w)?,
+        HtmlStyle::Inline(theme) => theme.highlight(&h, Inline(&mut w))?,
+    }
+    w.write_str("</pre>")
+}
+
+fn classes(h: &Highlighted, mut w: impl Write) -> fmt::Result {
+    let mut last = 0;
+    for (range, category) in &h.spans {
+        escape(&h.text[last..range.start], &mut w)?;
+        write!(w, "<span class=\"{}\">", category.scope().replace('.', " "))?;
+        escape(&h.text[range.clone()], &mut w)?;
+        w.write_str("</span>")?;
+        last = range.end;
+    }
+    escape(&h.text[last..], w)
+}
+
+/// Writes each span of highlighted text as a `<span>` with inline styles
+struct Inline<W>(W);
+
+impl<W: Write> PaintWrite for Inline<W> {
+    fn write_str(&mut self, s: &str, paint: Paint) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        let mut css = String::new();
+        if let Some([r, g, b]) = paint.rgb.or_else(|| ansi::rgb(paint.style.foreground)) {
+            write!(css, "color:#{r:02x}{g:02x}{b:02x};")?;
+        }
+        if let Some([r, g, b]) = ansi::rgb(paint.style.background) {
+            write!(css, "background-color:#{r:02x}{g:02x}{b:02x};")?;
+        }
+        match paint.style.intensity {
+            Intensity::Bold => css += "font-weight:bold;",
+            Intensity::Faint => css += "opacity:0.5;",
+            _ => {}
+        }
+
+        if css.is_empty() {
+            return escape(s, &mut self.0);
+        }
+        write!(self.0, "<span style=\"{}\">", css.trim_end_matches(';'))?;
+        escape(s, &mut self.0)?;
+        self.0.write_str("</span>")
+    }
+}
+
+fn escape(s: &str, mut w: impl Write) -> fmt::Result {
+    for c in s.chars() {
+        match c {
+            '&' => w.write_str("&amp;")?,
+            '<' => w.write_str("&lt;")?,
+            '>' => w.write_str("&gt;")?,
+            '"' => w.write_str("&quot;")?,
+            '\'' => w.write_str("&#39;")?,
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, Inline};
+    use crate::colors::ansi::{Paint, PaintWrite};
+    use stylish::{Color, Foreground, Style};
+
+    #[test]
+    fn escapes() {
+        let mut s = String::new();
+        escape("<a href=\"x\">&'", &mut s).unwrap();
+        assert_eq!(s, "&lt;a href=&quot;x&quot;&gt;&amp;&#39;");
+    }
+
+    #[test]
+    fn inline() {
+        let mut s = String::new();
+        let paint = Paint {
+            style: Style::default().with(Foreground(Color::Red)),
+            rgb: None,
+        };
+        Inline(&mut s).write_str("a<b", paint).unwrap();
+        Inline(&mut s).write_str(" ", Paint::default()).unwrap();
+        assert_eq!(s, "<span style=\"color:#cd0000\">a&lt;b</span> ");
+    }
+}
@@ -0,0 +1,384 @@
+#[cfg(feature = "tmtheme")]
+use std::io::{BufRead, Seek};
+use std::sync::OnceLock;
+
+use stylish::{Color, Foreground, Intensity, Style};
+#[cfg(feature = "tmtheme")]
+use syntect::{
+    highlighting::{FontStyle, ThemeSet},
+    LoadingError,
+};
+
+use super::ansi::{nearest_16, ColorDepth, Paint, PaintWrite};
+use crate::pretty::Highlighted;
+
+/// A syntax highlighting theme for [`color`](crate::color) output.
+///
+/// A theme is a list of rules, each mapping a scope selector to a [`Style`].
+/// Scopes follow the [sublime text naming conventions](https://www.sublimetext.com/docs/scope_naming.html),
+/// such as `keyword`, `string.quoted` or `constant.numeric`. A rule applies to every scope
+/// that it is a prefix of, and the most specific matching rule wins.
+///
+/// The scopes given to the formatted values are:
+///
+/// * `storage.type` - struct names, eg `Demo` in `Demo { foo: 5 }`
+/// * `entity.name.variant` - tuple struct names and unit values, eg `Some` and `None`
+/// * `variable.other.member` - field names, eg `foo` in `Demo { foo: 5 }`
+/// * `meta.mapping.key` - the keys of maps
+/// * `string.quoted` - strings
+/// * `constant.character` - chars and bytes
+/// * `constant.numeric` - numbers
+/// * `constant.language` - booleans
+/// * `keyword` and `punctuation`
+///
+/// # Examples
+///
+/// ```rust
+/// use dbg_pls::{color_themed, Theme};
+/// use dbg_pls::stylish::{Color, Foreground, Style};
+///
+/// let theme = Theme::new()
+///     .rule("string", Style::default().with(Foreground(Color::Green)))
+///     .rule("constant.numeric", Style::default().with(Foreground(Color::Cyan)));
+///
+/// println!("{}", color_themed(&("hello", 42), &theme));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Theme {
+    rules: Vec<ThemeRule>,
+    depth: Option<ColorDepth>,
+}
+
+#[derive(Clone, Debug)]
+struct ThemeRule {
+    selector: String,
+    paint: Paint,
+}
+
+impl ThemeRule {
+    /// The number of scope atoms the selector matched, if it matched at all
+    fn score(&self, scope: &str) -> Option<usize> {
+        let mut scope = scope.split('.');
+        let mut atoms = 0;
+        for atom in self.selector.split('.') {
+            if scope.next() != Some(atom) {
+                return None;
+            }
+            atoms += 1;
+        }
+        Some(atoms)
+    }
+}
+
+fn fg(color: Color) -> Style {
+    Style::default().with(Foreground(color))
+}
+
+fn bold(color: Color) -> Style {
+    fg(color).with(Intensity::Bold)
+}
+
+impl Default for Theme {
+    /// The theme used by [`color`](crate::color)
+    fn default() -> Self {
+        Theme::new()
+            .rule("variable", fg(Color::Blue))
+            .rule("keyword", fg(Color::Red))
+            .rule("punctuation", fg(Color::Red))
+            .rule("storage", fg(Color::Green))
+            .rule("entity.name", fg(Color::Cyan))
+            .rule("meta.mapping.key", fg(Color::BrightBlue))
+            .rule("string", fg(Color::Yellow))
+            .rule("constant.numeric", fg(Color::Magenta))
+            .rule("constant.language", fg(Color::BrightMagenta))
+    }
+}
+
+impl Theme {
+    /// Creates a theme with no rules, which leaves all text unstyled
+    #[must_use]
+    pub fn new() -> Self {
+        Theme {
+            rules: vec![],
+            depth: None,
+        }
+    }
+
+    /// Loads a theme from a `.tmTheme` (sublime text/textmate color scheme) file.
+    ///
+    /// The RGB colors of the scheme are rendered with the color depth that the
+    /// terminal supports, see [`ColorDepth::detect`]. Use [`Theme::color_depth`]
+    /// to choose a specific one instead.
+    ///
+    /// # Limitations
+    ///
+    /// Schemes are mapped onto the flat rules of a [`Theme`], so only part of them is used:
+    ///
+    /// * Only the foreground color and bold font style of each rule are kept.
+    ///   Backgrounds, italics and underlines are dropped.
+    /// * The global settings of the scheme are ignored, including its default foreground,
+    ///   so unmatched text keeps the terminal's own color.
+    /// * Only selectors of a single scope are used. Descendant selectors such as
+    ///   `meta.mapping.key string` and selectors with exclusions such as
+    ///   `constant.numeric - comment` are skipped.
+    ///
+    /// # Errors
+    /// This will return an error if the file could not be read or is not a valid theme
+    ///
+    /// ```rust,no_run
+    /// use dbg_pls::{color_themed, ColorDepth, Theme};
+    /// use std::{fs::File, io::BufReader};
+    ///
+    /// let file = BufReader::new(File::open("Monokai Extended.tmTheme")?);
+    /// let theme = Theme::from_tmtheme(file)?.color_depth(ColorDepth::TrueColor);
+    ///
+    /// println!("{}", color_themed(&("hello", 42), &theme));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "tmtheme")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tmtheme")))]
+    pub fn from_tmtheme(mut reader: impl BufRead + Seek) -> Result<Self, LoadingError> {
+        let theme = ThemeSet::load_from_reader(&mut reader)?;
+
+        let mut rules = vec![];
+        for item in &theme.scopes {
+            let Some(color) = item.style.foreground else {
+                continue;
+            };
+            let rgb = [color.r, color.g, color.b];
+            let mut style = fg(nearest_16(rgb));
+            if item
+                .style
+                .font_style
+                .is_some_and(|s| s.contains(FontStyle::BOLD))
+            {
+                style = style.with(Intensity::Bold);
+            }
+            for selector in &item.scope.selectors {
+                // highlighted text has a single scope, without parents to match against
+                let [scope] = selector.path.as_slice() else {
+                    continue;
+                };
+                if !selector.excludes.is_empty() {
+                    continue;
+                }
+                rules.push(ThemeRule {
+                    selector: scope.build_string(),
+                    paint: Paint {
+                        style,
+                        rgb: Some(rgb),
+                    },
+                });
+            }
+        }
+
+        Ok(Theme { rules, depth: None })
+    }
+
+    /// The theme named by the `DBG_PLS_THEME` environment variable, if any.
+    ///
+    /// This is one of `default`, `monokai`, `solarized-dark`, `solarized-light` or `high-contrast`,
+    /// or with the `tmtheme` feature, the path to a `.tmTheme` file.
+    pub(super) fn from_env() -> Option<Self> {
+        let name = std::env::var("DBG_PLS_THEME").ok()?;
+        match name.as_str() {
+            "default" => Some(Theme::default()),
+            "monokai" => Some(Theme::monokai()),
+            "solarized-dark" => Some(Theme::solarized_dark()),
+            "solarized-light" => Some(Theme::solarized_light()),
+            "high-contrast" => Some(Theme::high_contrast()),
+            #[cfg(feature = "tmtheme")]
+            path => {
+                let file = std::fs::File::open(path).ok()?;
+                Theme::from_tmtheme(std::io::BufReader::new(file)).ok()
+            }
+            #[cfg(not(feature = "tmtheme"))]
+            _ => None,
+        }
+    }
+
+    /// Adds a rule, coloring all text that matches the scope selector with the given RGB color.
+    ///
+    /// Terminals that only support 16 colors get the nearest of those instead.
+    #[must_use]
+    pub fn rule_rgb(self, selector: &str, rgb: [u8; 3]) -> Self {
+        self.paint(selector, fg(nearest_16(rgb)), rgb)
+    }
+
+    fn paint(mut self, selector: &str, style: Style, rgb: [u8; 3]) -> Self {
+        self.rules.push(ThemeRule {
+            selector: selector.to_owned(),
+            paint: Paint {
+                style,
+                rgb: Some(rgb),
+            },
+        });
+        self
+    }
+
+    /// Sets the range of colors to render RGB colors with, such as those added by
+    /// [`Theme::rule_rgb`] or loaded by [`Theme::from_tmtheme`].
+    ///
+    /// By default this is detected from the environment with [`ColorDepth::detect`].
+    /// Rules added with [`Theme::rule`] always use their ANSI color.
+    #[must_use]
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub(super) fn depth(&self) -> ColorDepth {
+        static DETECTED: OnceLock<ColorDepth> = OnceLock::new();
+        self.depth
+            .unwrap_or_else(|| *DETECTED.get_or_init(ColorDepth::detect))
+    }
+
+    /// Adds a rule, styling all text that matches the scope selector.
+    ///
+    /// The most specific matching rule applies. Between equally specific rules, the one
+    /// added last wins, so a rule can override the same scope of a bundled theme.
+    #[must_use]
+    pub fn rule(mut self, selector: &str, style: Style) -> Self {
+        self.rules.push(ThemeRule {
+            selector: selector.to_owned(),
+            paint: Paint { style, rgb: None },
+        });
+        self
+    }
+
+    /// A theme inspired by [monokai](https://monokai.pro/), for dark backgrounds
+    #[must_use]
+    pub fn monokai() -> Self {
+        Theme::new()
+            .paint("keyword", fg(Color::Red), [0xF9, 0x26, 0x72])
+            .paint("punctuation", fg(Color::BrightBlack), [0x75, 0x71, 0x5E])
+            .paint("storage", fg(Color::Cyan), [0x66, 0xD9, 0xEF])
+            .paint("entity.name", fg(Color::Green), [0xA6, 0xE2, 0x2E])
+            .paint("variable", fg(Color::BrightYellow), [0xFD, 0x97, 0x1F])
+            .paint("string", fg(Color::Yellow), [0xE6, 0xDB, 0x74])
+            .paint("constant", fg(Color::Magenta), [0xAE, 0x81, 0xFF])
+    }
+
+    /// A theme inspired by [solarized](https://ethanschoonover.com/solarized/), for dark backgrounds
+    #[must_use]
+    pub fn solarized_dark() -> Self {
+        Theme::new()
+            .paint("variable", fg(Color::BrightBlue), [0x26, 0x8B, 0xD2])
+            .paint("keyword", fg(Color::BrightGreen), [0x85, 0x99, 0x00])
+            .paint("punctuation", fg(Color::BrightBlack), [0x58, 0x6E, 0x75])
+            .paint("storage", fg(Color::BrightYellow), [0xB5, 0x89, 0x00])
+            .paint("entity.name", fg(Color::Magenta), [0x6C, 0x71, 0xC4])
+            .paint("string", fg(Color::BrightCyan), [0x2A, 0xA1, 0x98])
+            .paint("constant", fg(Color::BrightMagenta), [0xD3, 0x36, 0x82])
+    }
+
+    /// A theme inspired by [solarized](https://ethanschoonover.com/solarized/), for light backgrounds
+    #[must_use]
+    pub fn solarized_light() -> Self {
+        Theme::new()
+            .paint("variable", fg(Color::Blue), [0x26, 0x8B, 0xD2])
+            .paint("keyword", fg(Color::Green), [0x85, 0x99, 0x00])
+            .paint("punctuation", fg(Color::Black), [0x65, 0x7B, 0x83])
+            .paint("storage", fg(Color::Red), [0xCB, 0x4B, 0x16])
+            .paint("entity.name", fg(Color::BrightBlue), [0x6C, 0x71, 0xC4])
+            .paint("string", fg(Color::Cyan), [0x2A, 0xA1, 0x98])
+            .paint("constant", fg(Color::Magenta), [0xD3, 0x36, 0x82])
+    }
+
+    /// A bold theme that only uses the bright colors, for readability on any background
+    #[must_use]
+    pub fn high_contrast() -> Self {
+        Theme::new()
+            .rule("variable", bold(Color::BrightBlue))
+            .rule("keyword", bold(Color::BrightRed))
+            .rule("punctuation", bold(Color::BrightRed))
+            .rule("storage", bold(Color::BrightGreen))
+            .rule("entity.name", bold(Color::BrightCyan))
+            .rule("string", bold(Color::BrightYellow))
+            .rule("constant", bold(Color::BrightMagenta))
+    }
+
+    /// The style of the most specific rule matching the scope
+    fn style_of(&self, scope: &str) -> Paint {
+        self.rules
+            .iter()
+            // `max_by_key` picks the last maximum, so later rules win ties
+            .filter_map(|rule| Some((rule.score(scope)?, rule.paint)))
+            .max_by_key(|&(score, _)| score)
+            .map(|(_, paint)| paint)
+            .unwrap_or_default()
+    }
+
+    pub(super) fn highlight(&self, h: &Highlighted, mut w: impl PaintWrite) -> std::fmt::Result {
+        let mut last = 0;
+        for (range, category) in &h.spans {
+            w.write_str(&h.text[last..range.start], Paint::default())?;
+            w.write_str(&h.text[range.clone()], self.style_of(category.scope()))?;
+            last = range.end;
+        }
+        w.write_str(&h.text[last..], Paint::default())?;
+        w.finish()
+    }
+}
+
+#[cfg(all(test, feature = "tmtheme"))]
+mod tests {
+    use std::io::Cursor;
+
+    use stylish::{Foreground, Intensity, Style};
+
+    use super::{nearest_16, Paint, Theme};
+
+    fn rule(scope: &str, foreground: &str, font_style: &str) -> String {
+        format!(
+            "<dict><key>scope</key><string>{scope}</string><key>settings</key><dict>\
+             <key>foreground</key><string>{foreground}</string>\
+             <key>fontStyle</key><string>{font_style}</string></dict></dict>"
+        )
+    }
+
+    fn load(rules: &[String]) -> Theme {
+        let theme = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <plist version=\"1.0\"><dict><key>settings</key><array>\
+             <dict><key>settings</key><dict>\
+             <key>foreground</key><string>#F8F8F2</string>\
+             <key>background</key><string>#272822</string></dict></dict>\
+             {}</array></dict></plist>",
+            rules.concat(),
+        );
+        Theme::from_tmtheme(Cursor::new(theme)).unwrap()
+    }
+
+    fn paint(rgb: [u8; 3], intensity: Intensity) -> Paint {
+        Paint {
+            style: Style::default()
+                .with(Foreground(nearest_16(rgb)))
+                .with(intensity),
+            rgb: Some(rgb),
+        }
+    }
+
+    #[test]
+    fn tmtheme_limits() {
+        let theme = load(&[
+            rule("string", "#E6DB74", "italic"),
+            rule("meta.mapping.key string", "#F92672", ""),
+            rule("constant.numeric - comment", "#AE81FF", ""),
+            rule("constant.numeric", "#AE81FF", "bold underline"),
+        ]);
+
+        // the global foreground is not applied to unmatched text
+        assert_eq!(theme.style_of("keyword"), Paint::default());
+
+        // the descendant selector does not override the plain one, and italics are dropped
+        let yellow = paint([0xE6, 0xDB, 0x74], Intensity::Normal);
+        assert_eq!(theme.style_of("string.quoted"), yellow);
+        assert_eq!(theme.style_of("meta.mapping.key"), Paint::default());
+
+        // the selector with an exclusion is skipped, and only the bold style is kept
+        let purple = paint([0xAE, 0x81, 0xFF], Intensity::Bold);
+        assert_eq!(theme.style_of("constant.numeric"), purple);
+        assert_eq!(theme.rules.len(), 2);
+    }
+}
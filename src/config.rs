@@ -0,0 +1,744 @@
+use std::{cell::RefCell, cmp::Ordering, sync::RwLock};
+
+use quote::ToTokens;
+
+#[cfg(feature = "pretty")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "pretty")]
+use syn::visit_mut::VisitMut;
+
+#[cfg(feature = "pretty")]
+use crate::{DebugPls, DebugWith, Formatter};
+
+/// Options that control how [`DebugPls`](crate::DebugPls) values are formatted.
+///
+/// A config can be applied to a single call, using methods like [`Config::pretty`],
+/// or to every call that doesn't specify one, using [`Config::set_global`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dbg_pls::Config;
+/// use std::collections::HashSet;
+///
+/// let set = HashSet::from(["c", "a", "b"]);
+/// let config = Config::new().sort_unordered(true);
+///
+/// # #[cfg(feature = "pretty")]
+/// assert_eq!(
+///     format!("{}", config.pretty(&set)),
+/// r#"{
+///     "a";
+///     "b";
+///     "c"
+/// }"#,
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    sort_unordered: bool,
+    pointers: Pointers,
+    width: Option<usize>,
+    max_depth: Option<usize>,
+    max_items: Option<usize>,
+    header: Header,
+    #[cfg(feature = "colors")]
+    color: Option<ColorChoice>,
+}
+
+/// How pointer addresses, such as from `*const T` or `fn()`, are formatted.
+///
+/// # Examples
+///
+/// ```rust
+/// use dbg_pls::{Config, Pointers};
+///
+/// let a = 1;
+/// let b = 2;
+/// let pointers = [&a as *const i32, &b, &a];
+///
+/// # #[cfg(feature = "pretty")]
+/// assert_eq!(
+///     format!("{}", Config::new().pointers(Pointers::Redact).pretty(&pointers)),
+///     "[0x1_ptr, 0x2_ptr, 0x1_ptr]",
+/// );
+/// # #[cfg(feature = "pretty")]
+/// assert_eq!(
+///     format!("{}", Config::new().pointers(Pointers::Hide).pretty(&pointers)),
+///     "[_, _, _]",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pointers {
+    /// Formats the address as-is, eg `0x7ffd5a3c`
+    #[default]
+    Show,
+    /// Replaces the address with a placeholder, numbered by first appearance in the output, eg `0x1_ptr`.
+    /// The same address always gets the same placeholder within a single formatted value.
+    Redact,
+    /// Replaces the address with `_`
+    Hide,
+}
+
+/// The fields shown in the header of each line printed by the debug macros, such as `pretty!`.
+///
+/// By default, only the file and line are shown, eg `[src/main.rs:10]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dbg_pls::{Config, Header};
+///
+/// // prints headers like `[src/main.rs:10:5 main +0.012s]`
+/// Config::new()
+///     .header(Header::new().column(true).thread(true).elapsed(true))
+///     .set_global();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Header {
+    pub(crate) column: bool,
+    pub(crate) thread: bool,
+    pub(crate) timestamp: bool,
+    pub(crate) elapsed: bool,
+}
+
+impl Header {
+    /// Creates the default header, with only the file and line
+    #[must_use]
+    pub const fn new() -> Self {
+        Header {
+            column: false,
+            thread: false,
+            timestamp: false,
+            elapsed: false,
+        }
+    }
+
+    /// Shows the column after the line, eg `src/main.rs:10:5`
+    #[must_use]
+    pub fn column(mut self, show: bool) -> Self {
+        self.column = show;
+        self
+    }
+
+    /// Shows the name of the current thread, or its id if it is unnamed
+    #[must_use]
+    pub fn thread(mut self, show: bool) -> Self {
+        self.thread = show;
+        self
+    }
+
+    /// Shows the wall-clock time in UTC, eg `12:34:56.789`
+    #[must_use]
+    pub fn timestamp(mut self, show: bool) -> Self {
+        self.timestamp = show;
+        self
+    }
+
+    /// Shows the time elapsed, eg `+1.234s`.
+    ///
+    /// This is measured from the first call to [`Config::set_global`] or to one of the
+    /// `pretty!` and `color!` family of macros, rather than from the start of the program.
+    /// Call [`Config::set_global`] early in `main` to measure from there.
+    #[must_use]
+    pub fn elapsed(mut self, show: bool) -> Self {
+        self.elapsed = show;
+        self
+    }
+}
+
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+/// Whether [`color`](crate::color) and the `color!` family of macros emit ANSI colors.
+///
+/// Unless set otherwise, the `color!` family of macros and the `assert_*_color!` macros
+/// use [`ColorChoice::Auto`], while [`color`](crate::color) and the other functions always emit colors.
+///
+/// # Examples
+///
+/// ```rust
+/// use dbg_pls::{ColorChoice, Config};
+///
+/// // always highlight, even when stderr is not a terminal
+/// Config::new().color_choice(ColorChoice::Always).set_global();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Always emits colors
+    Always,
+    /// Emits colors if stderr is a terminal.
+    ///
+    /// Setting the `NO_COLOR` environment variable disables colors,
+    /// and setting `CLICOLOR_FORCE` enables them even when stderr is not a terminal.
+    #[default]
+    Auto,
+    /// Never emits colors, falling back to the plain [`pretty`](crate::pretty) output
+    Never,
+}
+
+#[cfg(feature = "colors")]
+impl ColorChoice {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "always" => Some(ColorChoice::Always),
+            "auto" => Some(ColorChoice::Auto),
+            "never" => Some(ColorChoice::Never),
+            _ => None,
+        }
+    }
+
+    /// Whether colors are emitted when writing to the stream
+    pub(crate) fn enabled(self, stream: &impl std::io::IsTerminal) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                let set = |var| std::env::var_os(var).is_some_and(|v| !v.is_empty() && v != "0");
+                if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+                    false
+                } else if set("CLICOLOR_FORCE") {
+                    true
+                } else {
+                    stream.is_terminal()
+                }
+            }
+        }
+    }
+}
+
+static GLOBAL: RwLock<Config> = RwLock::new(Config::new());
+
+#[cfg(feature = "pretty")]
+const DEFAULT_WIDTH: usize = 120;
+
+impl Config {
+    /// Creates the default config
+    #[must_use]
+    pub const fn new() -> Self {
+        Config {
+            sort_unordered: false,
+            pointers: Pointers::Show,
+            width: None,
+            max_depth: None,
+            max_items: None,
+            header: Header::new(),
+            #[cfg(feature = "colors")]
+            color: None,
+        }
+    }
+
+    /// Sorts the entries of collections that have no defined order, such as
+    /// [`HashMap`](std::collections::HashMap) and [`HashSet`](std::collections::HashSet),
+    /// so that the same value is always formatted the same way.
+    ///
+    /// Entries are sorted by their formatted key, with numbers ordered by their value
+    /// and everything else by its text. Use a [`BTreeMap`](std::collections::BTreeMap)
+    /// if you want the entries ordered by [`Ord`] instead.
+    ///
+    /// Custom collections can opt in to this using [`DebugMap::finish_unordered`](crate::DebugMap::finish_unordered)
+    /// and [`DebugSet::finish_unordered`](crate::DebugSet::finish_unordered), or
+    /// [`DebugMap::entries_sorted`](crate::DebugMap::entries_sorted) and
+    /// [`DebugSet::entries_sorted`](crate::DebugSet::entries_sorted) to sort by [`Ord`].
+    #[must_use]
+    pub fn sort_unordered(mut self, sort: bool) -> Self {
+        self.sort_unordered = sort;
+        self
+    }
+
+    /// Sets how pointer addresses are formatted.
+    /// Addresses change from run to run, so redacting them allows the output to be diffed.
+    #[must_use]
+    pub fn pointers(mut self, pointers: Pointers) -> Self {
+        self.pointers = pointers;
+        self
+    }
+
+    /// Sets the line width that the output is wrapped to.
+    ///
+    /// Defaults to 120. The `pretty!` and `color!` macros instead default to the width of
+    /// the terminal if stderr is one, and can be overridden by the `DBG_PLS_WIDTH` environment variable.
+    ///
+    /// ```rust
+    /// use dbg_pls::Config;
+    ///
+    /// # #[cfg(feature = "pretty")]
+    /// assert_eq!(
+    ///     format!("{}", Config::new().width(10).pretty(&[1000, 2000])),
+    ///     "[\n    1000,\n    2000,\n]",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Limits how deeply nested values are formatted. Values nested deeper are replaced with `..`.
+    ///
+    /// ```rust
+    /// use dbg_pls::Config;
+    ///
+    /// # #[cfg(feature = "pretty")]
+    /// assert_eq!(
+    ///     format!("{}", Config::new().max_depth(1).pretty(&Some(Some(Some(1))))),
+    ///     "Some(Some(..))",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Limits how many entries of lists, sets and maps are formatted.
+    /// The remaining entries are replaced with a single `..`.
+    ///
+    /// ```rust
+    /// use dbg_pls::Config;
+    /// use std::collections::BTreeMap;
+    ///
+    /// # #[cfg(feature = "pretty")]
+    /// assert_eq!(
+    ///     format!("{}", Config::new().max_items(2).pretty(&vec![1, 2, 3, 4])),
+    ///     "[1, 2, ..]",
+    /// );
+    ///
+    /// # #[cfg(feature = "pretty")]
+    /// assert_eq!(
+    ///     format!("{}", Config::new().max_items(1).pretty(&BTreeMap::from([(1, 2), (3, 4)]))),
+    /// r#"{
+    ///     [1] = 2;
+    ///     ..
+    /// }"#,
+    /// );
+    /// ```
+    #[must_use]
+    pub fn max_items(mut self, items: usize) -> Self {
+        self.max_items = Some(items);
+        self
+    }
+
+    /// Sets the fields shown in the header of each line printed by the debug macros, see [`Header`]
+    #[must_use]
+    pub fn header(mut self, header: Header) -> Self {
+        self.header = header;
+        self
+    }
+
+    #[cfg(feature = "pretty")]
+    /// The fields shown in the header of each line printed by the debug macros
+    pub(crate) fn header_fields(&self) -> Header {
+        self.header
+    }
+
+    #[cfg(feature = "pretty")]
+    /// The line width that the output is wrapped to
+    pub(crate) fn max_width(&self) -> usize {
+        self.width.unwrap_or(DEFAULT_WIDTH)
+    }
+
+    #[cfg(feature = "colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    /// Sets whether colored output emits ANSI colors, see [`ColorChoice`]
+    #[must_use]
+    pub fn color_choice(mut self, choice: ColorChoice) -> Self {
+        self.color = Some(choice);
+        self
+    }
+
+    #[cfg(feature = "colors")]
+    /// Whether colored output should emit ANSI colors with this config
+    pub(crate) fn use_color(&self) -> bool {
+        self.color
+            .unwrap_or(ColorChoice::Always)
+            .enabled(&std::io::stderr())
+    }
+
+    /// Returns the config used by calls that don't specify one
+    ///
+    /// # Panics
+    /// This will panic if the global config lock is poisoned
+    #[must_use]
+    pub fn global() -> Config {
+        GLOBAL.read().unwrap().clone()
+    }
+
+    /// Sets the config used by calls that don't specify one
+    ///
+    /// # Panics
+    /// This will panic if the global config lock is poisoned
+    pub fn set_global(self) {
+        #[cfg(feature = "pretty")]
+        crate::output::start();
+        *GLOBAL.write().unwrap() = self;
+    }
+
+    #[cfg(feature = "pretty")]
+    /// Formats the value with this config
+    pub(crate) fn process(&self, value: &dyn DebugPls) -> syn::Expr {
+        self.renumber_pointers(self.scope(|| Formatter::process(value)))
+    }
+
+    #[cfg(feature = "pretty")]
+    /// Formats the value with this config
+    pub(crate) fn process_with<T>(&self, value: &dyn DebugWith<T>, with: &T) -> syn::Expr {
+        self.renumber_pointers(self.scope(|| Formatter::process_with(value, with)))
+    }
+
+    #[cfg(feature = "pretty")]
+    /// Renumbers the pointer placeholders by their first appearance in the output.
+    ///
+    /// They are first numbered in the order the pointers are visited,
+    /// which differs from the output when unordered entries are sorted afterwards.
+    fn renumber_pointers(&self, mut expr: syn::Expr) -> syn::Expr {
+        struct Renumber(Vec<String>);
+        impl VisitMut for Renumber {
+            fn visit_lit_int_mut(&mut self, lit: &mut syn::LitInt) {
+                if lit.suffix() != "ptr" {
+                    return;
+                }
+                let n = lit.base10_digits();
+                let index = self.0.iter().position(|p| p == n).unwrap_or_else(|| {
+                    self.0.push(n.to_owned());
+                    self.0.len() - 1
+                });
+                *lit = placeholder(index + 1);
+            }
+        }
+
+        if self.pointers == Pointers::Redact {
+            Renumber(Vec::new()).visit_expr_mut(&mut expr);
+        }
+        expr
+    }
+
+    #[cfg(feature = "pretty")]
+    /// Runs `f` with this config applied to all formatting on the current thread
+    fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Scope>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPE.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(SCOPE.replace(Some(Scope {
+            config: self.clone(),
+            pointers: Vec::new(),
+            depth: 0,
+        })));
+        f()
+    }
+}
+
+#[cfg(feature = "pretty")]
+/// Implementation detail for the `pretty!` and `color!` macros.
+///
+/// The global config, with the settings from the environment applied.
+/// Wraps to the width of the terminal if no width was set,
+/// and only emits colors to a terminal if no color choice was set.
+#[must_use]
+pub fn macro_config() -> Config {
+    with_terminal_width(env_config(), terminal_width)
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+/// Implementation detail for the `log_pretty!` family of macros and
+/// [`pretty_value`](crate::tracing::pretty_value).
+///
+/// The global config, with the settings from the environment applied.
+/// Logs rarely end up in a terminal, so this wraps to the configured width,
+/// rather than the width of the terminal.
+#[must_use]
+pub fn log_config() -> Config {
+    env_config()
+}
+
+#[cfg(feature = "colors")]
+/// The color choice of the `assert_*_color!` macros.
+/// Like the `color!` macros, they follow `DBG_PLS_COLOR` and default to [`ColorChoice::Auto`].
+pub(crate) fn assert_color() -> ColorChoice {
+    env_config().color.unwrap_or_default()
+}
+
+#[cfg(feature = "pretty")]
+/// The global config, with the settings from the environment applied
+fn env_config() -> Config {
+    crate::output::start();
+    Env::get().apply(Config::global())
+}
+
+#[cfg(feature = "pretty")]
+/// Wraps to the width of the terminal if no width was set and there is a terminal
+fn with_terminal_width(mut config: Config, width: impl FnOnce() -> Option<usize>) -> Config {
+    config.width = config.width.or_else(width);
+    config
+}
+
+#[cfg(feature = "pretty")]
+/// The width of stderr, if it is a terminal
+fn terminal_width() -> Option<usize> {
+    let (terminal_size::Width(width), _) = terminal_size::terminal_size_of(std::io::stderr())?;
+    Some(usize::from(width))
+}
+
+#[cfg(feature = "pretty")]
+/// Settings for the debug macros, read once from the `DBG_PLS_*` environment variables
+struct Env {
+    width: Option<usize>,
+    max_depth: Option<usize>,
+    max_items: Option<usize>,
+    #[cfg(feature = "colors")]
+    color: Option<ColorChoice>,
+}
+
+#[cfg(feature = "pretty")]
+impl Env {
+    fn get() -> &'static Env {
+        static INSTANCE: OnceLock<Env> = OnceLock::new();
+        INSTANCE.get_or_init(|| Env::from_vars(|name| std::env::var(name).ok()))
+    }
+
+    /// Parses the settings, ignoring any variable that is not set or not valid
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Env {
+        Env {
+            width: var("DBG_PLS_WIDTH").and_then(|v| v.parse().ok()),
+            max_depth: var("DBG_PLS_DEPTH").and_then(|v| v.parse().ok()),
+            max_items: var("DBG_PLS_MAX_ITEMS").and_then(|v| v.parse().ok()),
+            #[cfg(feature = "colors")]
+            color: var("DBG_PLS_COLOR").and_then(|v| ColorChoice::from_name(&v)),
+        }
+    }
+
+    /// Overrides the settings of the config with those that are set
+    fn apply(&self, mut config: Config) -> Config {
+        config.width = self.width.or(config.width);
+        config.max_depth = self.max_depth.or(config.max_depth);
+        config.max_items = self.max_items.or(config.max_items);
+        #[cfg(feature = "colors")]
+        {
+            config.color = self.color.or(config.color).or(Some(ColorChoice::Auto));
+        }
+        config
+    }
+}
+
+struct Scope {
+    config: Config,
+    /// Pointer addresses seen so far, in order of first appearance
+    pointers: Vec<usize>,
+    /// How many values the current value is nested within
+    depth: usize,
+}
+
+thread_local! {
+    static SCOPE: RefCell<Option<Scope>> = const { RefCell::new(None) };
+}
+
+/// Whether collections with no defined order should have their entries sorted
+pub(crate) fn sort_unordered() -> bool {
+    SCOPE.with_borrow(|scope| scope.as_ref().is_some_and(|s| s.config.sort_unordered))
+}
+
+/// What the entries of collections with no defined order are sorted by:
+/// numbers by their value, and then everything else by its formatted text
+pub(crate) struct SortKey {
+    number: Option<f64>,
+    text: String,
+}
+
+impl SortKey {
+    pub(crate) fn new(expr: &syn::Expr) -> Self {
+        let number = match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int),
+                ..
+            }) => int.base10_digits().parse().ok(),
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Float(float),
+                ..
+            }) => float.base10_digits().parse().ok(),
+            _ => None,
+        };
+        SortKey {
+            number,
+            text: crate::tag::untagged(expr).to_token_stream().to_string(),
+        }
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let number = match (self.number, other.number) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        number.then_with(|| self.text.cmp(&other.text))
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for SortKey {}
+
+/// The maximum number of entries to format in a collection
+pub(crate) fn max_items() -> Option<usize> {
+    SCOPE.with_borrow(|scope| scope.as_ref().and_then(|s| s.config.max_items))
+}
+
+/// Runs `f` to format a value nested one level deeper,
+/// or returns `None` if that would exceed the max depth
+pub(crate) fn nested<R>(f: impl FnOnce() -> R) -> Option<R> {
+    struct Leave;
+    impl Drop for Leave {
+        fn drop(&mut self) {
+            SCOPE.with_borrow_mut(|scope| {
+                if let Some(scope) = scope {
+                    scope.depth -= 1;
+                }
+            });
+        }
+    }
+
+    let enter = SCOPE.with_borrow_mut(|scope| {
+        let Some(scope) = scope else {
+            return true;
+        };
+        if scope.config.max_depth.is_some_and(|max| scope.depth > max) {
+            return false;
+        }
+        scope.depth += 1;
+        true
+    });
+    if !enter {
+        return None;
+    }
+    let _leave = Leave;
+    Some(f())
+}
+
+/// How a pointer address should be formatted
+pub(crate) enum Pointer {
+    Address,
+    Placeholder(usize),
+    Hidden,
+}
+
+/// The literal standing in for the `n`th redacted pointer, eg `0x1_ptr`
+pub(crate) fn placeholder(n: usize) -> syn::LitInt {
+    syn::LitInt::new(&format!("{n:#x}_ptr"), proc_macro2::Span::call_site())
+}
+
+/// Decides how the pointer address should be formatted
+pub(crate) fn pointer(addr: usize) -> Pointer {
+    SCOPE.with_borrow_mut(|scope| {
+        let Some(scope) = scope else {
+            return Pointer::Address;
+        };
+        match scope.config.pointers {
+            Pointers::Show => Pointer::Address,
+            Pointers::Hide => Pointer::Hidden,
+            Pointers::Redact => {
+                let index = scope
+                    .pointers
+                    .iter()
+                    .position(|&p| p == addr)
+                    .unwrap_or_else(|| {
+                        scope.pointers.push(addr);
+                        scope.pointers.len() - 1
+                    });
+                Pointer::Placeholder(index + 1)
+            }
+        }
+    })
+}
+
+#[cfg(all(test, feature = "pretty"))]
+mod tests {
+    use super::{with_terminal_width, Config, Env, DEFAULT_WIDTH};
+
+    fn env(vars: &[(&str, &str)]) -> Env {
+        Env::from_vars(|name| {
+            let (_, value) = vars.iter().find(|(var, _)| *var == name)?;
+            Some((*value).to_owned())
+        })
+    }
+
+    #[test]
+    fn env_vars() {
+        let env = env(&[
+            ("DBG_PLS_WIDTH", "60"),
+            ("DBG_PLS_DEPTH", "3"),
+            ("DBG_PLS_MAX_ITEMS", "10"),
+            #[cfg(feature = "colors")]
+            ("DBG_PLS_COLOR", "never"),
+        ]);
+        assert_eq!(env.width, Some(60));
+        assert_eq!(env.max_depth, Some(3));
+        assert_eq!(env.max_items, Some(10));
+        #[cfg(feature = "colors")]
+        assert_eq!(env.color, Some(crate::ColorChoice::Never));
+    }
+
+    #[test]
+    fn env_vars_invalid() {
+        let env = env(&[
+            ("DBG_PLS_WIDTH", "wide"),
+            ("DBG_PLS_DEPTH", "-1"),
+            ("DBG_PLS_MAX_ITEMS", ""),
+            #[cfg(feature = "colors")]
+            ("DBG_PLS_COLOR", "sometimes"),
+        ]);
+        assert_eq!(env.width, None);
+        assert_eq!(env.max_depth, None);
+        assert_eq!(env.max_items, None);
+        #[cfg(feature = "colors")]
+        assert_eq!(env.color, None);
+    }
+
+    #[test]
+    fn env_over_defaults() {
+        let defaults = Config::new().width(40).max_depth(2).max_items(5);
+
+        // unset variables keep the defaults
+        let config = env(&[]).apply(defaults.clone());
+        assert_eq!(config.width, Some(40));
+        assert_eq!(config.max_depth, Some(2));
+        assert_eq!(config.max_items, Some(5));
+        #[cfg(feature = "colors")]
+        assert_eq!(config.color, Some(crate::ColorChoice::Auto));
+
+        let config = env(&[("DBG_PLS_WIDTH", "60"), ("DBG_PLS_MAX_ITEMS", "10")]).apply(defaults);
+        assert_eq!(config.width, Some(60));
+        assert_eq!(config.max_depth, Some(2));
+        assert_eq!(config.max_items, Some(10));
+    }
+
+    #[test]
+    fn terminal_width_fallback() {
+        // without a terminal, the default width is kept
+        let config = with_terminal_width(Config::new(), || None);
+        assert_eq!(config.max_width(), DEFAULT_WIDTH);
+
+        let config = with_terminal_width(Config::new(), || Some(80));
+        assert_eq!(config.max_width(), 80);
+
+        // a configured width wins over the terminal
+        let config = with_terminal_width(Config::new().width(40), || Some(80));
+        assert_eq!(config.max_width(), 40);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{DebugPls, DebugWith, Formatter};
+use crate::{config, DebugPls, DebugWith, Formatter};
 
 /// A helper designed to assist with creation of
 /// [`DebugPls`] implementations for list-like structures.
@@ -22,6 +22,7 @@ use crate::{DebugPls, DebugWith, Formatter};
 pub struct DebugList<'a> {
     formatter: Formatter<'a>,
     expr: syn::ExprArray,
+    elided: bool,
 }
 
 impl<'a> DebugList<'a> {
@@ -33,20 +34,32 @@ impl<'a> DebugList<'a> {
                 bracket_token: syn::token::Bracket::default(),
                 elems: syn::punctuated::Punctuated::default(),
             },
+            elided: false,
         }
     }
 
+    /// Whether the list has as many entries as [`Config::max_items`](crate::Config::max_items) allows
+    fn is_full(&mut self) -> bool {
+        let full = config::max_items().is_some_and(|max| self.expr.elems.len() >= max);
+        self.elided |= full;
+        full
+    }
+
     /// Adds a new entry to the list output.
     #[must_use]
     pub fn entry(mut self, entry: &dyn DebugPls) -> Self {
-        self.expr.elems.push(Formatter::process(entry));
+        if !self.is_full() {
+            self.expr.elems.push(Formatter::process(entry));
+        }
         self
     }
 
     /// Adds a new entry to the list output.
     #[must_use]
     pub fn entry_with<T>(mut self, value: &dyn DebugWith<T>, with: &T) -> Self {
-        self.expr.elems.push(Formatter::process_with(value, with));
+        if !self.is_full() {
+            self.expr.elems.push(Formatter::process_with(value, with));
+        }
         self
     }
 
@@ -68,24 +81,31 @@ impl<'a> DebugList<'a> {
         D: DebugWith<T>,
         I: IntoIterator<Item = D>,
     {
-        self.expr.elems.extend(
-            entries
-                .into_iter()
-                .map(|entry| Formatter::process_with(&entry, with)),
-        );
+        for entry in entries {
+            if self.is_full() {
+                break;
+            }
+            self.expr.elems.push(Formatter::process_with(&entry, with));
+        }
         self
     }
 
     /// Closes off the list
-    pub fn finish(self) {
+    pub fn finish(mut self) {
+        if self.elided {
+            self.expr.elems.push(Formatter::elided());
+        }
         self.formatter.write_expr(self.expr);
     }
 }
 
 impl<'f, D: DebugPls> Extend<D> for DebugList<'f> {
     fn extend<T: IntoIterator<Item = D>>(&mut self, iter: T) {
-        self.expr
-            .elems
-            .extend(iter.into_iter().map(|entry| Formatter::process(&entry)));
+        for entry in iter {
+            if self.is_full() {
+                break;
+            }
+            self.expr.elems.push(Formatter::process(&entry));
+        }
     }
 }
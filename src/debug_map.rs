@@ -1,4 +1,4 @@
-use crate::{DebugPls, DebugWith, Formatter};
+use crate::{config, tag::Tag, DebugPls, DebugWith, Formatter};
 
 /// A helper designed to assist with creation of
 /// [`DebugPls`] implementations for maps.
@@ -30,22 +30,32 @@ use crate::{DebugPls, DebugWith, Formatter};
 /// ```
 pub struct DebugMap<'a> {
     formatter: Formatter<'a>,
-    set: syn::Block,
+    entries: Vec<(syn::Expr, syn::Expr)>,
     key: Option<syn::Expr>,
+    elided: bool,
 }
 
 impl<'a> DebugMap<'a> {
     pub(crate) fn new(formatter: Formatter<'a>) -> Self {
         DebugMap {
             formatter,
-            set: syn::Block {
-                brace_token: syn::token::Brace::default(),
-                stmts: vec![],
-            },
+            entries: vec![],
             key: None,
+            elided: false,
         }
     }
 
+    /// Whether the map has as many entries as [`Config::max_items`](crate::Config::max_items) allows.
+    ///
+    /// When sorting, all entries are kept until [`finish`](Self::finish) so the first ones are
+    /// kept after sorting, rather than the first ones to be added.
+    fn is_full(&mut self) -> bool {
+        let full = !config::sort_unordered()
+            && config::max_items().is_some_and(|max| self.entries.len() >= max);
+        self.elided |= full;
+        full
+    }
+
     /// Adds the key part to the map output.
     ///
     /// # Panics
@@ -54,8 +64,13 @@ impl<'a> DebugMap<'a> {
     /// by a corresponding call to `value`. Otherwise this method will panic.
     #[must_use]
     pub fn key(mut self, key: &dyn DebugPls) -> Self {
+        let key = if self.is_full() {
+            Formatter::elided()
+        } else {
+            Formatter::process(key)
+        };
         assert!(
-            self.key.replace(Formatter::process(key)).is_none(),
+            self.key.replace(key).is_none(),
             "attempted to begin a new map entry without completing the previous one"
         );
         self
@@ -73,21 +88,11 @@ impl<'a> DebugMap<'a> {
             .key
             .take()
             .expect("attempted to format a map value before its key");
+        if self.is_full() {
+            return self;
+        }
         let value = Formatter::process(value);
-        let entry = syn::ExprAssign {
-            attrs: vec![],
-            left: Box::new(syn::Expr::Array(syn::ExprArray {
-                attrs: vec![],
-                bracket_token: syn::token::Bracket::default(),
-                elems: [key].into_iter().collect(),
-            })),
-            eq_token: syn::token::Eq::default(),
-            right: Box::new(value),
-        };
-        self.set.stmts.push(syn::Stmt::Expr(
-            entry.into(),
-            Some(syn::token::Semi::default()),
-        ));
+        self.entries.push((key, value));
         self
     }
 
@@ -99,10 +104,13 @@ impl<'a> DebugMap<'a> {
     /// by a corresponding call to `value`. Otherwise this method will panic.
     #[must_use]
     pub fn key_with<T>(mut self, key: &dyn DebugWith<T>, with: &T) -> Self {
+        let key = if self.is_full() {
+            Formatter::elided()
+        } else {
+            Formatter::process_with(key, with)
+        };
         assert!(
-            self.key
-                .replace(Formatter::process_with(key, with))
-                .is_none(),
+            self.key.replace(key).is_none(),
             "attempted to begin a new map entry without completing the previous one"
         );
         self
@@ -120,21 +128,11 @@ impl<'a> DebugMap<'a> {
             .key
             .take()
             .expect("attempted to format a map value before its key");
+        if self.is_full() {
+            return self;
+        }
         let value = Formatter::process_with(value, with);
-        let entry = syn::ExprAssign {
-            attrs: vec![],
-            left: Box::new(syn::Expr::Array(syn::ExprArray {
-                attrs: vec![],
-                bracket_token: syn::token::Bracket::default(),
-                elems: [key].into_iter().collect(),
-            })),
-            eq_token: syn::token::Eq::default(),
-            right: Box::new(value),
-        };
-        self.set.stmts.push(syn::Stmt::Expr(
-            entry.into(),
-            Some(syn::token::Semi::default()),
-        ));
+        self.entries.push((key, value));
         self
     }
 
@@ -157,6 +155,45 @@ impl<'a> DebugMap<'a> {
             .fold(self, |f, (key, value)| f.entry(&key, &value))
     }
 
+    /// Adds all the entries to the map output, sorted by their keys if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled.
+    /// Use this for collections that have no defined order, but whose keys implement [`Ord`].
+    ///
+    /// ```rust
+    /// use dbg_pls::{Config, DebugPls, Formatter};
+    /// use std::collections::HashMap;
+    ///
+    /// struct Foo(HashMap<i32, &'static str>);
+    ///
+    /// impl DebugPls for Foo {
+    ///     fn fmt(&self, f: Formatter) {
+    ///         f.debug_map().entries_sorted(&self.0).finish()
+    ///     }
+    /// }
+    /// let value = Foo(HashMap::from([(10, "b"), (2, "a")]));
+    /// assert_eq!(
+    ///     format!("{}", Config::new().sort_unordered(true).pretty(&value)),
+    /// "{
+    ///     [2] = \"a\";
+    ///     [10] = \"b\";
+    /// }",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn entries_sorted<K, V, I>(self, entries: I) -> Self
+    where
+        K: DebugPls + Ord,
+        V: DebugPls,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        if !config::sort_unordered() {
+            return self.entries(entries);
+        }
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.entries(entries)
+    }
+
     /// Adds the entry to the set output.
     #[must_use]
     pub fn entry_with<T>(self, key: &dyn DebugWith<T>, value: &dyn DebugWith<T>, with: &T) -> Self {
@@ -176,12 +213,75 @@ impl<'a> DebugMap<'a> {
             .fold(self, |f, (key, value)| f.entry_with(&key, &value, with))
     }
 
+    /// Adds all the entries to the map output, sorted by their keys if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled.
+    /// Use this for collections that have no defined order, but whose keys implement [`Ord`].
+    #[must_use]
+    pub fn entries_sorted_with<T, K, V, I>(self, entries: I, with: &T) -> Self
+    where
+        K: DebugWith<T> + Ord,
+        V: DebugWith<T>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        if !config::sort_unordered() {
+            return self.entries_with(entries, with);
+        }
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.entries_with(entries, with)
+    }
+
     /// Closes off the map.
-    pub fn finish(self) {
+    pub fn finish(mut self) {
+        if let Some(max) = config::max_items() {
+            if self.entries.len() > max {
+                self.entries.truncate(max);
+                self.elided = true;
+            }
+        }
+
+        let mut stmts: Vec<syn::Stmt> = self
+            .entries
+            .into_iter()
+            .map(|(key, value)| {
+                let entry = syn::ExprAssign {
+                    attrs: vec![Tag::MapEntry.attr()],
+                    left: Box::new(syn::Expr::Array(syn::ExprArray {
+                        attrs: vec![],
+                        bracket_token: syn::token::Bracket::default(),
+                        elems: [key].into_iter().collect(),
+                    })),
+                    eq_token: syn::token::Eq::default(),
+                    right: Box::new(value),
+                };
+                syn::Stmt::Expr(entry.into(), Some(syn::token::Semi::default()))
+            })
+            .collect();
+        if self.elided {
+            stmts.push(syn::Stmt::Expr(Formatter::elided(), None));
+        }
         self.formatter.write_expr(syn::ExprBlock {
             attrs: vec![],
             label: None,
-            block: self.set,
+            block: syn::Block {
+                brace_token: syn::token::Brace::default(),
+                stmts,
+            },
         });
     }
+
+    /// Closes off the map. Use this instead of [`finish`](Self::finish) for
+    /// collections that have no defined order.
+    ///
+    /// The entries will be sorted by their formatted keys if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled,
+    /// with numbers ordered by their value. See [`entries_sorted`](Self::entries_sorted)
+    /// to order them by [`Ord`] instead.
+    pub fn finish_unordered(mut self) {
+        if config::sort_unordered() {
+            self.entries
+                .sort_by_cached_key(|(key, _)| config::SortKey::new(key));
+        }
+        self.finish();
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{DebugPls, DebugWith, Formatter};
+use crate::{config, DebugPls, DebugWith, Formatter};
 
 /// A helper designed to assist with creation of
 /// [`DebugPls`] implementations for sets.
@@ -30,27 +30,38 @@ use crate::{DebugPls, DebugWith, Formatter};
 /// ```
 pub struct DebugSet<'a> {
     formatter: Formatter<'a>,
-    set: syn::Block,
+    entries: Vec<syn::Expr>,
+    elided: bool,
 }
 
 impl<'a> DebugSet<'a> {
     pub(crate) fn new(formatter: Formatter<'a>) -> Self {
         DebugSet {
             formatter,
-            set: syn::Block {
-                brace_token: syn::token::Brace::default(),
-                stmts: vec![],
-            },
+            entries: vec![],
+            elided: false,
         }
     }
 
+    /// Whether the set has as many entries as [`Config::max_items`](crate::Config::max_items) allows.
+    ///
+    /// When sorting, all entries are kept until [`finish`](Self::finish) so the first ones are
+    /// kept after sorting, rather than the first ones to be added.
+    fn is_full(&mut self) -> bool {
+        let full = !config::sort_unordered()
+            && config::max_items().is_some_and(|max| self.entries.len() >= max);
+        self.elided |= full;
+        full
+    }
+
     /// Adds the entry to the set output.
     #[must_use]
     pub fn entry(mut self, value: &dyn DebugPls) -> Self {
+        if self.is_full() {
+            return self;
+        }
         let expr = Formatter::process(value);
-        self.set
-            .stmts
-            .push(syn::Stmt::Expr(expr, Some(syn::token::Semi::default())));
+        self.entries.push(expr);
         self
     }
 
@@ -64,13 +75,31 @@ impl<'a> DebugSet<'a> {
         entries.into_iter().fold(self, |f, entry| f.entry(&entry))
     }
 
+    /// Adds all the entries to the set output, sorted if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled.
+    /// Use this for collections that have no defined order, but whose entries implement [`Ord`].
+    #[must_use]
+    pub fn entries_sorted<V, I>(self, entries: I) -> Self
+    where
+        V: DebugPls + Ord,
+        I: IntoIterator<Item = V>,
+    {
+        if !config::sort_unordered() {
+            return self.entries(entries);
+        }
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort();
+        self.entries(entries)
+    }
+
     /// Adds the entry to the set output.
     #[must_use]
     pub fn entry_with<T>(mut self, value: &dyn DebugWith<T>, with: &T) -> Self {
+        if self.is_full() {
+            return self;
+        }
         let expr = Formatter::process_with(value, with);
-        self.set
-            .stmts
-            .push(syn::Stmt::Expr(expr, Some(syn::token::Semi::default())));
+        self.entries.push(expr);
         self
     }
 
@@ -86,17 +115,67 @@ impl<'a> DebugSet<'a> {
             .fold(self, |f, entry| f.entry_with(&entry, with))
     }
 
+    /// Adds all the entries to the set output, sorted if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled.
+    /// Use this for collections that have no defined order, but whose entries implement [`Ord`].
+    #[must_use]
+    pub fn entries_sorted_with<T, V, I>(self, entries: I, with: &T) -> Self
+    where
+        V: DebugWith<T> + Ord,
+        I: IntoIterator<Item = V>,
+    {
+        if !config::sort_unordered() {
+            return self.entries_with(entries, with);
+        }
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort();
+        self.entries_with(entries, with)
+    }
+
     /// Closes off the set.
     pub fn finish(mut self) {
+        if let Some(max) = config::max_items() {
+            if self.entries.len() > max {
+                self.entries.truncate(max);
+                self.elided = true;
+            }
+        }
+        if self.elided {
+            self.entries.push(Formatter::elided());
+        }
+
+        let mut stmts: Vec<syn::Stmt> = self
+            .entries
+            .into_iter()
+            .map(|entry| syn::Stmt::Expr(entry, Some(syn::token::Semi::default())))
+            .collect();
+
         // remove the last semicolon
-        if let Some(syn::Stmt::Expr(entry, Some(_))) = self.set.stmts.pop() {
-            self.set.stmts.push(syn::Stmt::Expr(entry, None));
+        if let Some(syn::Stmt::Expr(entry, Some(_))) = stmts.pop() {
+            stmts.push(syn::Stmt::Expr(entry, None));
         }
 
         self.formatter.write_expr(syn::ExprBlock {
             attrs: vec![],
             label: None,
-            block: self.set,
+            block: syn::Block {
+                brace_token: syn::token::Brace::default(),
+                stmts,
+            },
         });
     }
+
+    /// Closes off the set. Use this instead of [`finish`](Self::finish) for
+    /// collections that have no defined order.
+    ///
+    /// The entries will be sorted by their formatted text if
+    /// [`Config::sort_unordered`](crate::Config::sort_unordered) is enabled,
+    /// with numbers ordered by their value. See [`entries_sorted`](Self::entries_sorted)
+    /// to order them by [`Ord`] instead.
+    pub fn finish_unordered(mut self) {
+        if config::sort_unordered() {
+            self.entries.sort_by_cached_key(config::SortKey::new);
+        }
+        self.finish();
+    }
 }
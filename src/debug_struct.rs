@@ -1,4 +1,4 @@
-use crate::{DebugPls, DebugWith, Formatter};
+use crate::{tag::Tag, DebugPls, DebugWith, Formatter};
 
 /// A helper designed to assist with creation of
 /// [`DebugPls`] implementations for structs.
@@ -40,7 +40,7 @@ impl<'a> DebugStruct<'a> {
         DebugStruct {
             formatter,
             expr: syn::ExprStruct {
-                attrs: vec![],
+                attrs: vec![Tag::Struct.attr()],
                 qself: None,
                 path: syn::Ident::into(syn::parse_str(name).unwrap()),
                 brace_token: syn::token::Brace::default(),
@@ -1,4 +1,4 @@
-use crate::{DebugPls, DebugWith, Formatter};
+use crate::{tag::Tag, DebugPls, DebugWith, Formatter};
 
 /// A helper designed to assist with creation of
 /// [`DebugPls`] implementations for tuple structs.
@@ -34,7 +34,7 @@ impl<'a> DebugTupleStruct<'a> {
             expr: syn::ExprCall {
                 attrs: vec![],
                 func: Box::new(syn::Expr::Path(syn::ExprPath {
-                    attrs: vec![],
+                    attrs: vec![Tag::Variant.attr()],
                     qself: None,
                     path: syn::Ident::into(syn::parse_str(name).unwrap()),
                 })),
@@ -1,4 +1,5 @@
 mod collections;
+mod error;
 mod fnptr;
 mod tuple;
 
@@ -10,7 +11,7 @@ use std::{
     task::Poll,
 };
 
-use crate::{DebugWith, Formatter};
+use crate::{config, DebugWith, Formatter};
 use syn::RangeLimits;
 use syn::__private::Span;
 
@@ -31,11 +32,21 @@ impl<W, D: ?Sized> DebugWith<W> for *const D {
         /// Since the formatting will be identical for all pointer types, use a non-monomorphized
         /// implementation for the actual formatting to reduce the amount of codegen work needed
         fn inner(ptr: *const (), f: Formatter<'_>) {
-            let output = format!("{:#x?}", ptr as usize);
-            f.write_expr(syn::ExprLit {
-                attrs: vec![],
-                lit: syn::LitFloat::new(&output, Span::call_site()).into(),
-            });
+            let lit: syn::Lit = match config::pointer(ptr as usize) {
+                config::Pointer::Address => {
+                    let output = format!("{:#x?}", ptr as usize);
+                    syn::LitFloat::new(&output, Span::call_site()).into()
+                }
+                config::Pointer::Placeholder(n) => config::placeholder(n).into(),
+                config::Pointer::Hidden => {
+                    f.write_expr(syn::ExprInfer {
+                        attrs: vec![],
+                        underscore_token: syn::token::Underscore::default(),
+                    });
+                    return;
+                }
+            };
+            f.write_expr(syn::ExprLit { attrs: vec![], lit });
         }
 
         inner((*self).cast(), f);
@@ -6,7 +6,7 @@ impl<W, K: DebugWith<W>, V: DebugWith<W>, S: ::std::hash::BuildHasher> DebugWith
     for HashMap<K, V, S>
 {
     fn fmt(&self, with: &W, f: Formatter<'_>) {
-        f.debug_map().entries_with(self, with).finish();
+        f.debug_map().entries_with(self, with).finish_unordered();
     }
 }
 
@@ -18,7 +18,7 @@ impl<W, K: DebugWith<W>, V: DebugWith<W>> DebugWith<W> for BTreeMap<K, V> {
 
 impl<W, V: DebugWith<W>, S: ::std::hash::BuildHasher> DebugWith<W> for HashSet<V, S> {
     fn fmt(&self, with: &W, f: Formatter<'_>) {
-        f.debug_set().entries_with(self, with).finish();
+        f.debug_set().entries_with(self, with).finish_unordered();
     }
 }
 
@@ -0,0 +1,29 @@
+use std::error::Error;
+
+use crate::{DebugWith, Formatter};
+
+/// Shared by the `dyn Error`, `dyn Error + Send` and `dyn Error + Send + Sync` impls
+fn debug_error(err: &dyn Error, f: Formatter<'_>) {
+    f.debug_struct("Error")
+        .field("message", &err.to_string())
+        .field("source", &err.source())
+        .finish();
+}
+
+impl<W> DebugWith<W> for dyn Error + '_ {
+    fn fmt(&self, _with: &W, f: Formatter<'_>) {
+        debug_error(self, f);
+    }
+}
+
+impl<W> DebugWith<W> for dyn Error + Send + '_ {
+    fn fmt(&self, _with: &W, f: Formatter<'_>) {
+        debug_error(self, f);
+    }
+}
+
+impl<W> DebugWith<W> for dyn Error + Send + Sync + '_ {
+    fn fmt(&self, _with: &W, f: Formatter<'_>) {
+        debug_error(self, f);
+    }
+}
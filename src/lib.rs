@@ -163,9 +163,13 @@
 //! # How it works
 //!
 //! All [`DebugPls`] implementations are forced to output only valid
-//! [`syn::Expr`] values. These are then formatted using [`prettyplease::unparse`].
-//! Finally, it uses [`syntect`] to provide syntax highlighting, with theme provided by
-//! <https://github.com/jonschlinkert/sublime-monokai-extended>
+//! [`syn::Expr`] values. These are then formatted using a copy of
+//! [`prettyplease`](https://docs.rs/prettyplease), adapted for expressions.
+//! Finally, the `colors` feature highlights the output based on which part of the
+//! syntax tree each token came from, using a `Theme` of [`stylish`](https://docs.rs/stylish) styles.
+//! The default theme is based on <https://github.com/jonschlinkert/sublime-monokai-extended>,
+//! and the `tmtheme` feature can load `.tmTheme` files using [`syntect`](https://docs.rs/syntect).
+mod config;
 mod debug_list;
 mod debug_map;
 mod debug_set;
@@ -173,22 +177,48 @@ mod debug_struct;
 mod debug_tuple;
 mod debug_tuple_struct;
 mod impls;
+mod resolve;
+mod tag;
+pub use config::{Config, Header, Pointers};
 pub use debug_list::DebugList;
 pub use debug_map::DebugMap;
 pub use debug_set::DebugSet;
 pub use debug_struct::DebugStruct;
 pub use debug_tuple::DebugTuple;
 pub use debug_tuple_struct::DebugTupleStruct;
+pub use resolve::{Resolve, Resolved};
 
+#[cfg(feature = "pretty")]
+mod output;
 #[cfg(feature = "pretty")]
 mod pretty;
 #[cfg(feature = "pretty")]
-pub use pretty::pretty;
+pub use output::{reset_output, set_output};
+#[cfg(feature = "pretty")]
+pub use pretty::{pretty, pretty_with};
 
 #[cfg(feature = "colors")]
 mod colors;
 #[cfg(feature = "colors")]
-pub use colors::{color, color_with};
+pub use colors::{
+    color, color_html, color_themed, color_with, write_html, ColorDepth, HtmlStyle, Theme,
+};
+#[cfg(feature = "colors")]
+pub use config::ColorChoice;
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+/// The styles used by [`Theme`] rules
+pub use stylish;
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod tracing;
+
+#[cfg(feature = "log")]
+mod log;
+
+#[cfg(feature = "insta")]
+mod snapshot;
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
@@ -211,14 +241,56 @@ pub use colors::{color, color_with};
 ///     "The origin is: Point { x: 0, y: 0 }",
 /// );
 /// ```
+///
+/// Fields marked with `#[dbg_pls(resolve)]` are IDs, formatted as the value they
+/// point to in the [`Resolve`] context. See [`Resolved`].
 pub use dbg_pls_derive::DebugPls;
 
+#[cfg(feature = "strip-in-release")]
+#[doc(hidden)]
+#[macro_export]
+/// Evaluates to `$debug` if `debug_assertions` are enabled, or else to `$release`
+macro_rules! __strip_in_release {
+    ($debug:expr, $release:expr) => {
+        match () {
+            #[cfg(debug_assertions)]
+            () => $debug,
+            #[cfg(not(debug_assertions))]
+            () => $release,
+        }
+    };
+}
+
+#[cfg(not(feature = "strip-in-release"))]
+#[doc(hidden)]
+#[macro_export]
+/// Evaluates to `$debug`
+macro_rules! __strip_in_release {
+    ($debug:expr, $release:expr) => {
+        $debug
+    };
+}
+
 #[doc(hidden)]
 pub mod __private {
     #[cfg(feature = "colors")]
-    pub use crate::colors::ColorStr;
+    pub use crate::colors::{assert_failed as assert_failed_color, ColorStr};
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    pub use crate::config::log_config;
+    #[cfg(feature = "pretty")]
+    pub use crate::config::macro_config;
+    #[cfg(feature = "pretty")]
+    pub use crate::output::{print, Location};
     #[cfg(feature = "pretty")]
-    pub use crate::pretty::Str as PrettyStr;
+    pub use crate::pretty::site::{Changed, Every, Once};
+    #[cfg(feature = "pretty")]
+    pub use crate::pretty::{assert_failed, All as PrettyAll, AssertKind, Str as PrettyStr};
+    #[cfg(feature = "log")]
+    pub use log;
+    #[cfg(feature = "insta")]
+    pub use crate::snapshot::snapshot;
+    #[cfg(feature = "insta")]
+    pub use insta;
 }
 
 /// Syntax aware pretty-printed debug formatting.
@@ -312,15 +384,31 @@ pub struct Formatter<'a> {
 
 impl<'a> Formatter<'a> {
     pub(crate) fn process(value: &dyn DebugPls) -> syn::Expr {
-        let mut expr = syn::Expr::Verbatim(proc_macro2::TokenStream::new());
-        value.fmt(Formatter { expr: &mut expr });
-        expr
+        config::nested(|| {
+            let mut expr = syn::Expr::Verbatim(proc_macro2::TokenStream::new());
+            value.fmt(Formatter { expr: &mut expr });
+            expr
+        })
+        .unwrap_or_else(Self::elided)
     }
 
     pub(crate) fn process_with<T>(value: &dyn DebugWith<T>, with: &T) -> syn::Expr {
-        let mut expr = syn::Expr::Verbatim(proc_macro2::TokenStream::new());
-        value.fmt(with, Formatter { expr: &mut expr });
-        expr
+        config::nested(|| {
+            let mut expr = syn::Expr::Verbatim(proc_macro2::TokenStream::new());
+            value.fmt(with, Formatter { expr: &mut expr });
+            expr
+        })
+        .unwrap_or_else(Self::elided)
+    }
+
+    /// The `..` expression, standing in for values that were left out
+    pub(crate) fn elided() -> syn::Expr {
+        syn::Expr::Range(syn::ExprRange {
+            attrs: vec![],
+            start: None,
+            limits: syn::RangeLimits::HalfOpen(syn::token::DotDot::default()),
+            end: None,
+        })
     }
 
     /// Writes a wrap expression into the formatter.
@@ -531,7 +619,7 @@ impl<'a> Formatter<'a> {
     pub fn debug_ident(self, name: &str) {
         let path: syn::Path = syn::Ident::into(syn::parse_str(name).unwrap());
         self.write_expr(syn::ExprPath {
-            attrs: vec![],
+            attrs: vec![tag::Tag::Variant.attr()],
             qself: None,
             path,
         });
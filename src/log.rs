@@ -0,0 +1,163 @@
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression. Same as [`pretty!`](crate::pretty!),
+/// but emitted through the [`log`](https://docs.rs/log) facade at the given level.
+///
+/// The target defaults to the module path of the invocation, and can be set with `target:`.
+/// Nothing is formatted if the level is disabled for the target.
+///
+/// Unlike [`pretty!`](crate::pretty!), the output is not wrapped to the width of the terminal,
+/// but to the width set by [`Config::width`](crate::Config::width) or `DBG_PLS_WIDTH`.
+/// See also [`error_pretty!`](crate::error_pretty!), [`warn_pretty!`](crate::warn_pretty!),
+/// [`info_pretty!`](crate::info_pretty!), [`debug_pretty!`](crate::debug_pretty!)
+/// and [`trace_pretty!`](crate::trace_pretty!).
+///
+/// ```rust
+/// use dbg_pls::log_pretty;
+/// use log::Level;
+///
+/// let a = 2;
+/// let b = log_pretty!(Level::Debug, a * 2) + 1;
+/// //      ^-- logs: [src/main.rs:5] a * 2 => 4
+/// assert_eq!(b, 5);
+///
+/// let (c, d) = log_pretty!(target: "app", Level::Info, a, b);
+/// assert_eq!((c, d), (2, 5));
+/// ```
+macro_rules! log_pretty {
+    (target: $target:expr, $lvl:expr, $val:expr $(,)?) => {
+        match $val {
+            tmp => {
+                let lvl = $lvl;
+                if $crate::__private::log::log_enabled!(target: $target, lvl) {
+                    let config = $crate::__private::log_config();
+                    $crate::__private::log::log!(
+                        target: $target,
+                        lvl,
+                        "{} {} => {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::PrettyStr(&config, ::std::stringify!($val)),
+                        config.pretty(&tmp)
+                    );
+                }
+                tmp
+            }
+        }
+    };
+    (target: $target:expr, $lvl:expr, $($val:expr),+ $(,)?) => {
+        ($($crate::log_pretty!(target: $target, $lvl, $val)),+,)
+    };
+    ($lvl:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: ::std::module_path!(), $lvl, $($val),+)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression at the `Error` level.
+/// Same as [`log_pretty!`] with [`Level::Error`](https://docs.rs/log/*/log/enum.Level.html#variant.Error).
+///
+/// ```rust
+/// use dbg_pls::error_pretty;
+///
+/// let a = error_pretty!(1 + 1);
+/// //      ^-- logs: [src/main.rs:3] 1 + 1 => 2
+/// assert_eq!(a, 2);
+/// ```
+macro_rules! error_pretty {
+    (target: $target:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: $target, $crate::__private::log::Level::Error, $($val),+)
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::log_pretty!($crate::__private::log::Level::Error, $($val),+)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression at the `Warn` level.
+/// Same as [`log_pretty!`] with [`Level::Warn`](https://docs.rs/log/*/log/enum.Level.html#variant.Warn).
+///
+/// ```rust
+/// use dbg_pls::warn_pretty;
+///
+/// let a = warn_pretty!(1 + 1);
+/// //      ^-- logs: [src/main.rs:3] 1 + 1 => 2
+/// assert_eq!(a, 2);
+/// ```
+macro_rules! warn_pretty {
+    (target: $target:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: $target, $crate::__private::log::Level::Warn, $($val),+)
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::log_pretty!($crate::__private::log::Level::Warn, $($val),+)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression at the `Info` level.
+/// Same as [`log_pretty!`] with [`Level::Info`](https://docs.rs/log/*/log/enum.Level.html#variant.Info).
+///
+/// ```rust
+/// use dbg_pls::info_pretty;
+///
+/// let a = info_pretty!(1 + 1);
+/// //      ^-- logs: [src/main.rs:3] 1 + 1 => 2
+/// assert_eq!(a, 2);
+/// ```
+macro_rules! info_pretty {
+    (target: $target:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: $target, $crate::__private::log::Level::Info, $($val),+)
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::log_pretty!($crate::__private::log::Level::Info, $($val),+)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression at the `Debug` level.
+/// Same as [`log_pretty!`] with [`Level::Debug`](https://docs.rs/log/*/log/enum.Level.html#variant.Debug).
+///
+/// ```rust
+/// use dbg_pls::debug_pretty;
+///
+/// let a = debug_pretty!(1 + 1);
+/// //      ^-- logs: [src/main.rs:3] 1 + 1 => 2
+/// assert_eq!(a, 2);
+/// ```
+macro_rules! debug_pretty {
+    (target: $target:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: $target, $crate::__private::log::Level::Debug, $($val),+)
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::log_pretty!($crate::__private::log::Level::Debug, $($val),+)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[macro_export]
+/// Logs and returns the value of a given expression at the `Trace` level.
+/// Same as [`log_pretty!`] with [`Level::Trace`](https://docs.rs/log/*/log/enum.Level.html#variant.Trace).
+///
+/// ```rust
+/// use dbg_pls::trace_pretty;
+///
+/// let a = trace_pretty!(1 + 1);
+/// //      ^-- logs: [src/main.rs:3] 1 + 1 => 2
+/// assert_eq!(a, 2);
+/// ```
+macro_rules! trace_pretty {
+    (target: $target:expr, $($val:expr),+ $(,)?) => {
+        $crate::log_pretty!(target: $target, $crate::__private::log::Level::Trace, $($val),+)
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::log_pretty!($crate::__private::log::Level::Trace, $($val),+)
+    };
+}
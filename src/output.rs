@@ -0,0 +1,183 @@
+use std::{
+    fmt,
+    io::Write,
+    sync::{Mutex, OnceLock, PoisonError},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::Config;
+
+static OUTPUT: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+/// Redirects the output of the `pretty!` and `color!` family of macros,
+/// which is written to stderr by default.
+///
+/// Note that [`ColorChoice::Auto`](crate::ColorChoice::Auto) still decides whether to
+/// emit colors based on stderr.
+///
+/// If writing to the output fails, the line is written to stderr instead.
+/// Use [`reset_output`] to switch back to stderr.
+///
+/// # Panics
+/// This will panic if the output lock is poisoned
+///
+/// ```rust
+/// use std::fs::File;
+///
+/// let path = std::env::temp_dir().join("debug.log");
+/// dbg_pls::set_output(Box::new(File::create(&path)?));
+/// # dbg_pls::reset_output();
+/// # std::fs::remove_file(path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn set_output(output: Box<dyn Write + Send>) {
+    *OUTPUT.lock().unwrap() = Some(output);
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+/// Switches the output of the `pretty!` and `color!` family of macros back to stderr,
+/// returning the output that was set by [`set_output`], if any.
+///
+/// # Panics
+/// This will panic if the output lock is poisoned
+pub fn reset_output() -> Option<Box<dyn Write + Send>> {
+    OUTPUT.lock().unwrap().take()
+}
+
+/// Implementation detail for the `pretty!` and `color!` macros.
+///
+/// Writes the line to the output set by [`set_output`], or to stderr
+pub fn print(args: fmt::Arguments<'_>) {
+    // formatting the values may print too, so don't hold the lock while doing so
+    let line = format!("{args}\n");
+    let mut output = OUTPUT.lock().unwrap_or_else(PoisonError::into_inner);
+    let written = match &mut *output {
+        Some(output) => output
+            .write_all(line.as_bytes())
+            .and_then(|()| output.flush())
+            .is_ok(),
+        None => false,
+    };
+    if !written {
+        eprint!("{line}");
+    }
+}
+
+/// When the elapsed time in the header is measured from.
+///
+/// There's no portable way to get the time the program started, so this is
+/// the first time the global config is set or a macro is called, whichever is earlier.
+pub(crate) fn start() -> Instant {
+    static START: OnceLock<Instant> = OnceLock::new();
+    *START.get_or_init(Instant::now)
+}
+
+/// Implementation detail for the `pretty!` and `color!` macros.
+///
+/// The header of each line, eg `[src/main.rs:10]`
+pub struct Location<'a> {
+    pub config: &'a Config,
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for Location<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = self.config.header_fields();
+        write!(f, "[{}:{}", self.file, self.line)?;
+        if header.column {
+            write!(f, ":{}", self.column)?;
+        }
+        if header.thread {
+            let thread = std::thread::current();
+            match thread.name() {
+                Some(name) => write!(f, " {name}")?,
+                None => write!(f, " {:?}", thread.id())?,
+            }
+        }
+        if header.timestamp {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            let secs = now.as_secs() % (24 * 60 * 60);
+            let millis = now.subsec_millis();
+            write!(
+                f,
+                " {:02}:{:02}:{:02}.{millis:03}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60,
+            )?;
+        }
+        if header.elapsed {
+            let elapsed = start().elapsed();
+            write!(f, " +{:.3}s", elapsed.as_secs_f64())?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use super::{print, reset_output, set_output, Location};
+    use crate::{Config, Header};
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn output() {
+        let capture = Capture::default();
+        set_output(Box::new(capture.clone()));
+        print(format_args!("captured {}", 1));
+        assert!(reset_output().is_some());
+        print(format_args!("not captured"));
+
+        // other tests might print while the output is redirected
+        let captured = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.contains("captured 1\n"));
+        assert!(!captured.contains("not captured"));
+    }
+
+    #[test]
+    fn location() {
+        let location = |header| {
+            let config = Config::new().header(header);
+            let location = Location {
+                config: &config,
+                file: "src/main.rs",
+                line: 10,
+                column: 5,
+            };
+            location.to_string()
+        };
+        assert_eq!(location(Header::new()), "[src/main.rs:10]");
+        assert_eq!(
+            location(Header::new().column(true).thread(true)),
+            "[src/main.rs:10:5 output::tests::location]"
+        );
+
+        let timestamp = location(Header::new().timestamp(true).elapsed(true));
+        let (time, elapsed) = timestamp["[src/main.rs:10 ".len()..]
+            .split_once(' ')
+            .unwrap();
+        assert_eq!(time.len(), "12:34:56.789".len());
+        assert!(elapsed.starts_with('+') && elapsed.ends_with("s]"));
+    }
+}
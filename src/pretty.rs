@@ -1,29 +1,118 @@
-use crate::{DebugPls, Formatter};
+use std::borrow::Cow;
+
+use crate::{Config, DebugPls, DebugWith};
+pub(crate) mod diff;
 mod please;
+pub(crate) mod site;
+
+pub(crate) use please::Highlighted;
+
+pub(crate) fn pretty_string(expr: &syn::Expr, width: usize) -> String {
+    please::unparse(expr, Some(width))
+}
 
-pub(crate) fn pretty_string(expr: &syn::Expr) -> String {
-    please::unparse(expr, Some(120))
+/// Like [`pretty_string`], but keeps the categories of the printed tokens
+pub(crate) fn pretty_highlighted(expr: &syn::Expr, width: usize) -> Highlighted {
+    please::unparse_highlighted(expr, Some(width))
+}
+
+/// Pretty prints the source code of a macro argument.
+///
+/// Source that isn't an expression is printed as a block of statements if possible,
+/// or else as is.
+pub(crate) fn source_highlighted(source: &str, width: usize) -> Highlighted {
+    please::unparse_source(&please::Source::parse(source), Some(width))
 }
 
 /// Implementation detail for the `pretty!` macro
-pub struct Str<'a>(pub &'a str);
+pub struct Str<'a>(pub &'a Config, pub &'a str);
+
+impl std::fmt::Display for Str<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&source_highlighted(self.1, self.0.max_width()).text)
+    }
+}
+
+/// Implementation detail for the `pretty_all!` macro
+pub trait Values {
+    fn values(&self) -> Vec<&dyn DebugPls>;
+}
+
+macro_rules! peel {
+    ($name:ident, $($other:ident,)*) => (values! { $($other,)* })
+}
+
+macro_rules! values {
+    () => ();
+    ( $($name:ident,)* ) => (
+        impl<$($name: DebugPls),+> Values for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn values(&self) -> Vec<&dyn DebugPls> {
+                let ($(ref $name,)+) = *self;
+                vec![$($name),+]
+            }
+        }
+        peel! { $($name,)+ }
+    )
+}
+
+values! { T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, }
+
+/// Implementation detail for the `pretty_all!` macro
+pub struct All<'a> {
+    pub config: &'a Config,
+    pub sources: &'a [&'a str],
+    pub values: &'a dyn Values,
+}
+
+impl std::fmt::Display for All<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = std::iter::zip(self.sources, self.values.values())
+            .map(|(source, value)| (please::Source::parse(source), self.config.process(value)))
+            .collect::<Vec<_>>();
+        let width = Some(self.config.max_width());
+        f.write_str(&please::unparse_labeled(&fields, width).text)
+    }
+}
+
+struct Pretty<'a> {
+    value: &'a dyn DebugPls,
+    config: Option<&'a Config>,
+}
 
-impl<'a> std::fmt::Display for Str<'a> {
+impl std::fmt::Debug for Pretty<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let expr = syn::parse_str(self.0).map_err(|_| std::fmt::Error)?;
-        f.write_str(&pretty_string(&expr))
+        let config = self
+            .config
+            .map_or_else(|| Cow::Owned(Config::global()), Cow::Borrowed);
+        let expr = config.process(self.value);
+        f.write_str(&pretty_string(&expr, config.max_width()))
     }
 }
 
-struct Pretty<'a>(&'a dyn DebugPls);
+impl std::fmt::Display for Pretty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+struct PrettyWith<'a, T> {
+    with: &'a T,
+    value: &'a dyn DebugWith<T>,
+    config: Option<&'a Config>,
+}
 
-impl<'a> std::fmt::Debug for Pretty<'a> {
+impl<T> std::fmt::Debug for PrettyWith<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&pretty_string(&Formatter::process(self.0)))
+        let config = self
+            .config
+            .map_or_else(|| Cow::Owned(Config::global()), Cow::Borrowed);
+        let expr = config.process_with(self.value, self.with);
+        f.write_str(&pretty_string(&expr, config.max_width()))
     }
 }
 
-impl<'a> std::fmt::Display for Pretty<'a> {
+impl<T> std::fmt::Display for PrettyWith<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Debug::fmt(self, f)
     }
@@ -32,7 +121,133 @@ impl<'a> std::fmt::Display for Pretty<'a> {
 #[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
 /// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`]
 pub fn pretty(value: &impl DebugPls) -> impl std::fmt::Debug + std::fmt::Display + '_ {
-    Pretty(value)
+    Pretty {
+        value,
+        config: None,
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+/// Wraps a [`DebugWith`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+/// formatted using the given context
+pub fn pretty_with<'a, T>(
+    with: &'a T,
+    value: &'a impl DebugWith<T>,
+) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+    PrettyWith {
+        with,
+        value,
+        config: None,
+    }
+}
+
+impl Config {
+    #[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+    /// Wraps a [`DebugPls`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+    /// formatted with this config
+    pub fn pretty<'a>(
+        &'a self,
+        value: &'a impl DebugPls,
+    ) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+        Pretty {
+            value,
+            config: Some(self),
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+    /// Wraps a [`DebugWith`] type into a [`std::fmt::Debug`] type for use in regular [`format!`],
+    /// formatted using the given context and this config
+    pub fn pretty_with<'a, T>(
+        &'a self,
+        with: &'a T,
+        value: &'a impl DebugWith<T>,
+    ) -> impl std::fmt::Debug + std::fmt::Display + 'a {
+        PrettyWith {
+            with,
+            value,
+            config: Some(self),
+        }
+    }
+}
+
+/// Implementation detail for the `assert_eq_pls!` and `assert_ne_pls!` macros
+#[derive(Clone, Copy)]
+pub enum AssertKind {
+    Eq,
+    Ne,
+}
+
+pub(crate) struct AssertFailed<'a> {
+    pub(crate) kind: AssertKind,
+    pub(crate) left: Highlighted,
+    pub(crate) right: Highlighted,
+    args: Option<std::fmt::Arguments<'a>>,
+}
+
+impl<'a> AssertFailed<'a> {
+    pub(crate) fn new(
+        kind: AssertKind,
+        left: &dyn DebugPls,
+        right: &dyn DebugPls,
+        args: Option<std::fmt::Arguments<'a>>,
+    ) -> Self {
+        let config = Config::global();
+        AssertFailed {
+            kind,
+            left: pretty_highlighted(&config.process(left), config.max_width()),
+            right: pretty_highlighted(&config.process(right), config.max_width()),
+            args,
+        }
+    }
+
+    /// Writes the first line of the panic message, matching [`std::assert_eq`]
+    pub(crate) fn header(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self.kind {
+            AssertKind::Eq => "==",
+            AssertKind::Ne => "!=",
+        };
+        write!(f, "assertion `left {op} right` failed")?;
+        if let Some(args) = self.args {
+            write!(f, ": {args}")?;
+        }
+        f.write_str("\n")
+    }
+}
+
+impl std::fmt::Display for AssertFailed<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.header(f)?;
+        match self.kind {
+            AssertKind::Eq => {
+                f.write_str("diff (- left / + right):\n")?;
+                for line in diff::lines(&self.left.text, &self.right.text) {
+                    match line {
+                        diff::Line::Both(line) => writeln!(f, " {line}")?,
+                        diff::Line::Left(line) => writeln!(f, "-{line}")?,
+                        diff::Line::Right(line) => writeln!(f, "+{line}")?,
+                    }
+                }
+                Ok(())
+            }
+            AssertKind::Ne => write!(f, "  left: {}\n right: {}", self.left.text, self.right.text),
+        }
+    }
+}
+
+/// Implementation detail for the `assert_eq_pls!` and `assert_ne_pls!` macros
+///
+/// # Panics
+///
+/// Always panics, describing the failed assertion
+#[track_caller]
+pub fn assert_failed(
+    kind: AssertKind,
+    left: &dyn DebugPls,
+    right: &dyn DebugPls,
+    args: Option<std::fmt::Arguments<'_>>,
+) -> ! {
+    panic!("{}", AssertFailed::new(kind, left, right, args))
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
@@ -53,7 +268,7 @@ pub fn pretty(value: &impl DebugPls) -> impl std::fmt::Debug + std::fmt::Display
 /// The macro works by using the [`DebugPls`] implementation of the type of
 /// the given expression to print the value to [stderr] along with the
 /// source location of the macro invocation as well as the source code
-/// of the expression.
+/// of the expression. The output can be redirected using [`set_output`].
 ///
 /// Invoking the macro on an expression moves and takes ownership of it
 /// before returning the evaluated expression unchanged. If the type
@@ -64,6 +279,8 @@ pub fn pretty(value: &impl DebugPls) -> impl std::fmt::Debug + std::fmt::Display
 /// The `pretty!` macro works exactly the same in release builds.
 /// This is useful when debugging issues that only occur in release
 /// builds or when debugging in release mode is significantly faster.
+/// Enabling the `strip-in-release` feature instead makes the macro evaluate to just
+/// the expression when `debug_assertions` are disabled.
 ///
 /// Note that the macro is intended as a debugging tool and therefore you
 /// should avoid having uses of it in version control for long periods
@@ -72,36 +289,401 @@ pub fn pretty(value: &impl DebugPls) -> impl std::fmt::Debug + std::fmt::Display
 /// such as the [`debug!`] macro from the [`log`] crate.
 ///
 /// [stderr]: https://en.wikipedia.org/wiki/Standard_streams#Standard_error_(stderr)
+/// [`set_output`]: crate::set_output
 /// [`debug!`]: https://docs.rs/log/*/log/macro.debug.html
 /// [`log`]: https://crates.io/crates/log
 macro_rules! pretty {
     () => {
-        ::std::eprintln!("[{}:{}]", ::std::file!(), ::std::line!())
+        $crate::__strip_in_release!(
+            $crate::__private::print(::std::format_args!(
+                "{}",
+                $crate::__private::Location {
+                    config: &$crate::__private::macro_config(),
+                    file: ::std::file!(),
+                    line: ::std::line!(),
+                    column: ::std::column!(),
+                }
+            )),
+            ()
+        )
     };
     ($val:expr $(,)?) => {
-        match $val {
-            tmp => {
-                ::std::eprintln!(
-                    "[{}:{}] {} => {}",
-                    ::std::file!(),
-                    ::std::line!(),
-                    $crate::__private::PrettyStr(::std::stringify!($val)),
-                    $crate::pretty(&tmp)
-                );
-                tmp
-            }
-        }
+        $crate::__strip_in_release!(
+            match $val {
+                tmp => {
+                    let config = $crate::__private::macro_config();
+                    $crate::__private::print(::std::format_args!(
+                        "{} {} => {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::PrettyStr(&config, ::std::stringify!($val)),
+                        config.pretty(&tmp)
+                    ));
+                    tmp
+                }
+            },
+            $val
+        )
     };
     ($($val:expr),+ $(,)?) => {
         ($($crate::pretty!($val)),+,)
     };
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Prints and returns the value of a given expression. Same as [`pretty!`],
+/// but formats the value using its [`DebugWith`] implementation with the given context.
+///
+/// ```rust
+/// use dbg_pls::{pretty_with, DebugWith, Formatter};
+///
+/// struct Name(usize);
+///
+/// impl DebugWith<Vec<&str>> for Name {
+///     fn fmt(&self, names: &Vec<&str>, f: Formatter<'_>) {
+///         f.debug_tuple_struct("Name").field(&names[self.0]).finish()
+///     }
+/// }
+///
+/// let names = vec!["alice", "bob"];
+/// let name = pretty_with!(&names, Name(1));
+/// //         ^-- prints: [src/main.rs:12] Name(1) => Name("bob")
+/// assert_eq!(name.0, 1);
+/// ```
+///
+/// [`DebugWith`]: crate::DebugWith
+macro_rules! pretty_with {
+    () => {
+        $crate::pretty!()
+    };
+    ($with:expr, $val:expr $(,)?) => {
+        $crate::__strip_in_release!(
+            match $val {
+                tmp => {
+                    let config = $crate::__private::macro_config();
+                    $crate::__private::print(::std::format_args!(
+                        "{} {} => {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::PrettyStr(&config, ::std::stringify!($val)),
+                        config.pretty_with($with, &tmp)
+                    ));
+                    tmp
+                }
+            },
+            $val
+        )
+    };
+    ($with:expr, $($val:expr),+ $(,)?) => {
+        ($($crate::pretty_with!($with, $val)),+,)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Returns the value of a given expression, printing it like [`pretty!`]
+/// only if the condition holds.
+///
+/// ```rust
+/// # use dbg_pls::pretty_if;
+/// let mut sum = 0;
+/// for i in 0..10_000 {
+///     sum += pretty_if!(i % 1000 == 0, i);
+///     //     ^-- prints: [src/main.rs:4] i => 0, then 1000, 2000, ...
+/// }
+/// assert_eq!(sum, 49_995_000);
+/// ```
+///
+/// The condition is evaluated before the values.
+macro_rules! pretty_if {
+    ($cond:expr, $val:expr $(,)?) => {
+        if $cond {
+            $crate::pretty!($val)
+        } else {
+            $val
+        }
+    };
+    ($cond:expr, $($val:expr),+ $(,)?) => {
+        if $cond {
+            $crate::pretty!($($val),+)
+        } else {
+            ($($val),+,)
+        }
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Returns the value of a given expression, printing it like [`pretty!`]
+/// at most once per interval for each call site.
+///
+/// ```rust
+/// # use dbg_pls::pretty_every;
+/// use std::time::Duration;
+///
+/// for i in 0..10_000 {
+///     pretty_every!(Duration::from_secs(1), i);
+///     //  ^-- prints: [src/main.rs:5] i => 0, and then again each second
+/// }
+/// ```
+macro_rules! pretty_every {
+    ($interval:expr, $val:expr $(,)?) => {
+        $crate::__strip_in_release!(
+            {
+                static EVERY: $crate::__private::Every = $crate::__private::Every::new();
+                $crate::pretty_if!(EVERY.ready($interval), $val)
+            },
+            $val
+        )
+    };
+    ($interval:expr, $($val:expr),+ $(,)?) => {
+        $crate::__strip_in_release!(
+            {
+                static EVERY: $crate::__private::Every = $crate::__private::Every::new();
+                $crate::pretty_if!(EVERY.ready($interval), $($val),+)
+            },
+            ($($val),+,)
+        )
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Returns the value of a given expression, printing it like [`pretty!`]
+/// only the first time the call site is reached.
+///
+/// ```rust
+/// # use dbg_pls::pretty_once;
+/// for i in 0..10 {
+///     pretty_once!(i);
+///     //  ^-- prints: [src/main.rs:3] i => 0
+/// }
+/// ```
+macro_rules! pretty_once {
+    ($val:expr $(,)?) => {
+        $crate::__strip_in_release!(
+            {
+                static ONCE: $crate::__private::Once = $crate::__private::Once::new();
+                $crate::pretty_if!(ONCE.first(), $val)
+            },
+            $val
+        )
+    };
+    ($($val:expr),+ $(,)?) => {
+        $crate::__strip_in_release!(
+            {
+                static ONCE: $crate::__private::Once = $crate::__private::Once::new();
+                $crate::pretty_if!(ONCE.first(), $($val),+)
+            },
+            ($($val),+,)
+        )
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Returns the value of a given expression, printing it like [`pretty!`]
+/// only if it is formatted differently to the last time the call site printed it.
+///
+/// ```rust
+/// # use dbg_pls::pretty_changed;
+/// for i in 0..10 {
+///     pretty_changed!(i / 4);
+///     //  ^-- prints: [src/main.rs:3] i / 4 => 0, then 1, then 2
+/// }
+/// ```
+///
+/// Note that the value is formatted every time, in order to compare it.
+macro_rules! pretty_changed {
+    ($val:expr $(,)?) => {
+        $crate::__strip_in_release!(
+            match $val {
+                tmp => {
+                    static LAST: $crate::__private::Changed = $crate::__private::Changed::new();
+                    let config = $crate::__private::macro_config();
+                    let value = ::std::string::ToString::to_string(&config.pretty(&tmp));
+                    if LAST.update(&value) {
+                        $crate::__private::print(::std::format_args!(
+                            "{} {} => {}",
+                            $crate::__private::Location {
+                                config: &config,
+                                file: ::std::file!(),
+                                line: ::std::line!(),
+                                column: ::std::column!(),
+                            },
+                            $crate::__private::PrettyStr(&config, ::std::stringify!($val)),
+                            value
+                        ));
+                    }
+                    tmp
+                }
+            },
+            $val
+        )
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::pretty_changed!($val)),+,)
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Prints and returns the values of the given expressions. Like [`pretty!`],
+/// but prints all of the values together in one block, labeled by their source code.
+///
+/// ```rust
+/// # use dbg_pls::pretty_all;
+/// let a = 2;
+/// let b = "hello";
+/// let (c, d) = pretty_all!(a * 2, b);
+/// //           ^-- prints: [src/main.rs:4] { a * 2: 4, b: "hello" }
+/// assert_eq!((c, d), (4, "hello"));
+/// ```
+///
+/// The values are returned as a tuple, even if there is only one.
+/// At most 12 values can be printed at once.
+macro_rules! pretty_all {
+    ($($val:expr),+ $(,)?) => {
+        $crate::__strip_in_release!(
+            match ($($val,)+) {
+                tmp => {
+                    let config = $crate::__private::macro_config();
+                    $crate::__private::print(::std::format_args!(
+                        "{} {}",
+                        $crate::__private::Location {
+                            config: &config,
+                            file: ::std::file!(),
+                            line: ::std::line!(),
+                            column: ::std::column!(),
+                        },
+                        $crate::__private::PrettyAll {
+                            config: &config,
+                            sources: &[$(::std::stringify!($val)),+],
+                            values: &tmp,
+                        }
+                    ));
+                    tmp
+                }
+            },
+            ($($val,)+)
+        )
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Asserts that two expressions are equal to each other (using [`PartialEq`]).
+/// Same as [`std::assert_eq`]
+///
+/// On panic, this macro will print a line diff of the values of the expressions,
+/// pretty printed using their [`DebugPls`] implementations.
+///
+/// ```rust
+/// # use dbg_pls::{assert_eq_pls, DebugPls};
+/// #[derive(DebugPls, PartialEq)]
+/// struct Demo {
+///     foo: i32,
+/// }
+///
+/// assert_eq_pls!(Demo { foo: 1 + 1 }, Demo { foo: 2 }, "we are testing addition");
+/// ```
+///
+/// [`DebugPls`]: crate::DebugPls
+macro_rules! assert_eq_pls {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::assert_failed(
+                        $crate::__private::AssertKind::Eq,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::assert_failed(
+                        $crate::__private::AssertKind::Eq,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::Some(::std::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pretty")))]
+#[macro_export]
+/// Asserts that two expressions are not equal to each other (using [`PartialEq`]).
+/// Same as [`std::assert_ne`]
+///
+/// On panic, this macro will print the values of the expressions,
+/// pretty printed using their [`DebugPls`] implementations.
+///
+/// ```rust
+/// # use dbg_pls::assert_ne_pls;
+/// assert_ne_pls!(vec![1, 2], vec![2, 1], "order matters");
+/// ```
+///
+/// [`DebugPls`]: crate::DebugPls
+macro_rules! assert_ne_pls {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if *left_val == *right_val {
+                    $crate::__private::assert_failed(
+                        $crate::__private::AssertKind::Ne,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::None,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if *left_val == *right_val {
+                    $crate::__private::assert_failed(
+                        $crate::__private::AssertKind::Ne,
+                        left_val,
+                        right_val,
+                        ::std::option::Option::Some(::std::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{collections::HashMap, time::Duration};
 
+    use super::{
+        site::{Changed, Every, Once},
+        source_highlighted, All,
+    };
     use crate::pretty;
+    use crate::{Config, Pointers};
 
     #[test]
     fn pretty_macro() {
@@ -114,4 +696,144 @@ mod tests {
         // map is moved through properly
         assert_eq!(map, HashMap::from([("hello", 1), ("world", 2),]));
     }
+
+    #[test]
+    fn pretty_all_macro() {
+        let a = 2;
+        let (b, c) = pretty_all!(a * 2, vec!["hello"]);
+        assert_eq!((b, c), (4, vec!["hello"]));
+    }
+
+    #[test]
+    fn pretty_if_macro() {
+        let values = (0..4).map(|i| pretty_if!(i % 2 == 0, vec![i]));
+        assert_eq!(values.collect::<Vec<_>>(), [[0], [1], [2], [3]]);
+        assert_eq!(pretty_if!(false, 1, "a"), (1, "a"));
+    }
+
+    #[test]
+    fn every() {
+        let every = Every::new();
+        assert!(every.ready(Duration::MAX));
+        assert!(!every.ready(Duration::MAX));
+        assert!(every.ready(Duration::ZERO));
+    }
+
+    #[test]
+    fn changed() {
+        let changed = Changed::new();
+        assert!(changed.update("1"));
+        assert!(!changed.update("1"));
+        assert!(changed.update("2"));
+
+        let once = Once::new();
+        assert!(once.first());
+        assert!(!once.first());
+    }
+
+    #[test]
+    fn sort_unordered() {
+        let config = Config::new().sort_unordered(true);
+        let map = HashMap::from([(10, "c"), (2, "b"), (1, "a")]);
+        assert_eq!(
+            config.pretty(&map).to_string(),
+            "{\n    [1] = \"a\";\n    [2] = \"b\";\n    [10] = \"c\";\n}",
+        );
+    }
+
+    #[test]
+    fn sort_unordered_max_items() {
+        let config = Config::new().sort_unordered(true).max_items(2);
+        let map: HashMap<_, _> = (0..20).map(|i| (i, i * 2)).collect();
+        assert_eq!(
+            config.pretty(&map).to_string(),
+            "{\n    [0] = 0;\n    [1] = 2;\n    ..\n}",
+        );
+
+        let set: std::collections::HashSet<_> = (1..=20).collect();
+        assert_eq!(
+            config.max_items(3).pretty(&set).to_string(),
+            "{\n    1;\n    2;\n    3;\n    ..\n}",
+        );
+    }
+
+    #[test]
+    fn sort_unordered_redact() {
+        let config = Config::new()
+            .sort_unordered(true)
+            .pointers(Pointers::Redact);
+        let values = [1, 2, 3];
+        let pointers = values.each_ref().map(std::ptr::from_ref);
+        let keys = ["c", "a", "b"];
+
+        // every map has its own random hash order
+        let pretty = |_| {
+            let map: HashMap<_, _> = keys.iter().zip(pointers).collect();
+            let output = config.pretty(&map).to_string();
+            output
+        };
+        let expected =
+            "{\n    [\"a\"] = 0x1_ptr;\n    [\"b\"] = 0x2_ptr;\n    [\"c\"] = 0x3_ptr;\n}";
+        for output in (0..10).map(pretty) {
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn labeled() {
+        let values = (4, "hello");
+        let all = |width| {
+            let config = Config::new().width(width);
+            let all = All {
+                config: &config,
+                sources: &["a * 2", "b"],
+                values: &values,
+            };
+            all.to_string()
+        };
+        assert_eq!(all(120), r#"{ a * 2: 4, b: "hello" }"#);
+        assert_eq!(all(20), "{\n    a * 2: 4,\n    b: \"hello\",\n}");
+    }
+
+    #[test]
+    fn source() {
+        let source = |s| source_highlighted(s, 120).text;
+        assert_eq!(source("x as u8 + y"), "x as u8 + y");
+        assert_eq!(source("let a = 1; a"), "{\n    let a = 1;\n    a\n}");
+        assert_eq!(source(" 1 + "), "1 +");
+    }
+
+    #[test]
+    fn assert_eq_pls_message() {
+        let err = std::panic::catch_unwind(|| {
+            assert_eq_pls!([1, 2, 3], [1, 4, 3], "{} is not {}", 2, 4);
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<String>().unwrap(),
+            "assertion `left == right` failed: 2 is not 4\n\
+            diff (- left / + right):\n\
+            -[1, 2, 3]\n\
+            +[1, 4, 3]\n"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "strip-in-release", not(debug_assertions)))]
+    fn strip_in_release() {
+        let mut evaluated = 0;
+        let mut eval = |x: i32| {
+            evaluated += 1;
+            x
+        };
+
+        pretty!();
+        assert_eq!(pretty!(eval(1)), 1);
+        assert_eq!(pretty!(eval(1), eval(2)), (1, 2));
+        assert_eq!(crate::pretty_all!(eval(1), eval(2)), (1, 2));
+        assert_eq!(crate::pretty_once!(eval(1)), 1);
+        assert_eq!(crate::pretty_every!(Duration::ZERO, eval(1)), 1);
+        assert_eq!(crate::pretty_changed!(eval(1)), 1);
+        assert_eq!(evaluated, 8);
+    }
 }
@@ -0,0 +1,115 @@
+/// A single line of a line-based diff
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Line<'a> {
+    Both(&'a str),
+    Left(&'a str),
+    Right(&'a str),
+}
+
+/// The largest LCS table, in cells, that [`lines`] will allocate
+const MAX_TABLE: usize = 1 << 20;
+
+/// Computes the line diff between two strings using the longest common subsequence.
+///
+/// Debug output is rarely more than a few hundred lines, so the simple
+/// quadratic table is perfectly adequate here once the common prefix and suffix
+/// are stripped. If the differing lines would need a table larger than [`MAX_TABLE`],
+/// they are all listed as removed and then added instead.
+pub(crate) fn lines<'a>(left: &'a str, right: &'a str) -> Vec<Line<'a>> {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+
+    let prefix = left.iter().zip(&right).take_while(|(l, r)| l == r).count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(l, r)| l == r)
+        .count();
+
+    let mut diff = Vec::with_capacity(left.len().max(right.len()));
+    diff.extend(left[..prefix].iter().copied().map(Line::Both));
+    let (left_rest, right_rest) = (
+        &left[prefix..left.len() - suffix],
+        &right[prefix..right.len() - suffix],
+    );
+    if (left_rest.len() + 1).saturating_mul(right_rest.len() + 1) > MAX_TABLE {
+        diff.extend(left_rest.iter().copied().map(Line::Left));
+        diff.extend(right_rest.iter().copied().map(Line::Right));
+    } else {
+        lcs(left_rest, right_rest, &mut diff);
+    }
+    diff.extend(left[left.len() - suffix..].iter().copied().map(Line::Both));
+    diff
+}
+
+/// Diffs the lines using the longest common subsequence, appending the result to `diff`
+fn lcs<'a>(left: &[&'a str], right: &[&'a str], diff: &mut Vec<Line<'a>>) {
+    // lcs[i][j] is the length of the longest common subsequence of left[i..] and right[j..]
+    let mut lcs = vec![vec![0_usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lcs[i][j] = if left[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] == right[j] {
+            diff.push(Line::Both(left[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(Line::Left(left[i]));
+            i += 1;
+        } else {
+            diff.push(Line::Right(right[j]));
+            j += 1;
+        }
+    }
+    diff.extend(left[i..].iter().copied().map(Line::Left));
+    diff.extend(right[j..].iter().copied().map(Line::Right));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, Line};
+
+    #[test]
+    fn diff_lines() {
+        let left = "Demo {\n    foo: 5,\n    bar: \"hello\",\n}";
+        let right = "Demo {\n    foo: 6,\n    bar: \"hello\",\n}";
+        assert_eq!(
+            lines(left, right),
+            [
+                Line::Both("Demo {"),
+                Line::Left("    foo: 5,"),
+                Line::Right("    foo: 6,"),
+                Line::Both("    bar: \"hello\","),
+                Line::Both("}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_large() {
+        let left = (0..2000)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let right = (0..2000)
+            .map(|i| (i * 2).to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let diff = lines(&left, &right);
+        // the first line is shared, the rest are too many to diff
+        assert_eq!(diff[0], Line::Both("0"));
+        assert_eq!(diff[1], Line::Left("1"));
+        assert_eq!(diff[2000], Line::Right("2"));
+        assert_eq!(diff.len(), 1 + 1999 * 2);
+    }
+}
@@ -1,11 +1,13 @@
 // Adapted from https://github.com/rust-lang/rust/blob/1.57.0/compiler/rustc_ast_pretty/src/pp.rs.
 // See "Algorithm notes" in the crate-level rustdoc.
 
+use crate::pretty::please::highlight::{Category, Highlighted};
 use crate::pretty::please::ring::RingBuffer;
 use std::borrow::Cow;
 use std::cmp;
 use std::collections::VecDeque;
 use std::iter;
+use std::ops::Range;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Breaks {
@@ -32,7 +34,7 @@ pub struct BeginToken {
 
 #[derive(Clone)]
 pub enum Token {
-    String(Cow<'static, str>),
+    String(Cow<'static, str>, Option<Category>),
     Break(BreakToken),
     Begin(BeginToken),
     End,
@@ -69,6 +71,12 @@ pub struct Printer {
     indent: usize,
     // Buffered indentation to avoid writing trailing whitespace
     pending_indentation: usize,
+    // Byte ranges of the output and the categories of the tokens printed there
+    spans: Vec<(Range<usize>, Category)>,
+    // Category given to identifiers, overriding the category guessed from the text
+    pub ident_category: Option<Category>,
+    // Whether a map key is being printed
+    pub map_key: bool,
 }
 
 #[derive(Clone)]
@@ -90,15 +98,21 @@ impl Printer {
             print_stack: Vec::new(),
             indent: 0,
             pending_indentation: 0,
+            spans: Vec::new(),
+            ident_category: None,
+            map_key: false,
         }
     }
 
-    pub fn eof(mut self) -> String {
+    pub fn eof(mut self) -> Highlighted {
         if !self.scan_stack.is_empty() {
             self.check_stack(0);
             self.advance_left();
         }
-        self.out
+        Highlighted {
+            text: self.out,
+            spans: self.spans,
+        }
     }
 
     pub fn scan_begin(&mut self, token: BeginToken) {
@@ -161,13 +175,13 @@ impl Printer {
         self.right_total += token.blank_space as isize;
     }
 
-    pub fn scan_string(&mut self, string: Cow<'static, str>) {
+    pub fn scan_string(&mut self, string: Cow<'static, str>, category: Option<Category>) {
         if self.scan_stack.is_empty() {
-            self.print_string(string);
+            self.print_string(string, category);
         } else {
             let len = string.len() as isize;
             self.buf.push(BufEntry {
-                token: Token::String(string),
+                token: Token::String(string, category),
                 size: len,
             });
             self.right_total += len;
@@ -179,7 +193,7 @@ impl Printer {
         match &mut self.buf.last_mut().token {
             Token::Break(token) => token.offset += offset,
             Token::Begin(_) => {}
-            Token::String(_) | Token::End => unreachable!(),
+            Token::String(..) | Token::End => unreachable!(),
         }
     }
 
@@ -195,7 +209,7 @@ impl Printer {
                             let actual_width = entry.size + self.right_total;
                             if actual_width > max {
                                 self.buf.push(BufEntry {
-                                    token: Token::String(Cow::Borrowed("")),
+                                    token: Token::String(Cow::Borrowed(""), None),
                                     size: SIZE_INFINITY,
                                 });
                                 self.right_total += SIZE_INFINITY;
@@ -206,7 +220,7 @@ impl Printer {
                 }
                 Token::End => depth += 1,
                 Token::Break(_) => {}
-                Token::String(_) => unreachable!(),
+                Token::String(..) => unreachable!(),
             }
         }
         self.scan_end();
@@ -232,9 +246,9 @@ impl Printer {
             let left = self.buf.pop_first();
 
             match left.token {
-                Token::String(string) => {
+                Token::String(string, category) => {
                     self.left_total += left.size;
-                    self.print_string(string);
+                    self.print_string(string, category);
                 }
                 Token::Break(token) => {
                     self.left_total += token.blank_space as isize;
@@ -274,7 +288,7 @@ impl Printer {
                         break;
                     }
                 }
-                Token::String(_) => unreachable!(),
+                Token::String(..) => unreachable!(),
             }
         }
     }
@@ -336,7 +350,7 @@ impl Printer {
             self.pending_indentation += token.blank_space;
             self.space -= token.blank_space as isize;
             if let Some(no_break) = token.no_break {
-                self.out.push(no_break);
+                self.push_punct(no_break);
                 self.space -= no_break.len_utf8() as isize;
             }
             if cfg!(prettyplease_debug) {
@@ -345,7 +359,7 @@ impl Printer {
         } else {
             if let Some(pre_break) = token.pre_break {
                 self.print_indent();
-                self.out.push(pre_break);
+                self.push_punct(pre_break);
             }
             if cfg!(prettyplease_debug) {
                 self.out.push('·');
@@ -356,18 +370,29 @@ impl Printer {
             self.space = cmp::max(self.margin - indent, self.margin / 3 * 2);
             if let Some(post_break) = token.post_break {
                 self.print_indent();
-                self.out.push(post_break);
+                self.push_punct(post_break);
                 self.space -= post_break.len_utf8() as isize;
             }
         }
     }
 
-    fn print_string(&mut self, string: Cow<'static, str>) {
+    fn print_string(&mut self, string: Cow<'static, str>, category: Option<Category>) {
         self.print_indent();
+        let start = self.out.len();
         self.out.push_str(&string);
+        if let Some(category) = category {
+            self.spans.push((start..self.out.len(), category));
+        }
         self.space -= string.len() as isize;
     }
 
+    fn push_punct(&mut self, ch: char) {
+        let start = self.out.len();
+        self.out.push(ch);
+        self.spans
+            .push((start..self.out.len(), Category::Punctuation));
+    }
+
     fn print_indent(&mut self) {
         self.out.reserve(self.pending_indentation);
         self.out
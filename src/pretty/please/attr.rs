@@ -1,13 +1,14 @@
 use crate::pretty::please::algorithm::Printer;
 use crate::pretty::please::path::PathKind;
 use crate::pretty::please::INDENT;
+use crate::tag::Tag;
 use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 use syn::{AttrStyle, Attribute, Expr, Lit, MacroDelimiter, Meta, MetaList, MetaNameValue};
 
 impl Printer {
     pub fn outer_attrs(&mut self, attrs: &[Attribute]) {
         for attr in attrs {
-            if let AttrStyle::Outer = attr.style {
+            if let (AttrStyle::Outer, None) = (attr.style, Tag::of(attr)) {
                 self.attr(attr);
             }
         }
@@ -34,7 +35,7 @@ impl Printer {
                     AttrStyle::Outer => "///",
                     AttrStyle::Inner(_) => "//!",
                 });
-                self.word(doc);
+                self.plain(doc);
                 self.hardbreak();
                 return;
             } else if can_be_block_comment(&doc)
@@ -48,7 +49,7 @@ impl Printer {
                     AttrStyle::Outer => "/**",
                     AttrStyle::Inner(_) => "/*!",
                 });
-                self.word(doc);
+                self.plain(doc);
                 self.word("*/");
                 self.hardbreak();
                 return;
@@ -57,13 +58,13 @@ impl Printer {
             if !comment.contains('\n') {
                 trim_trailing_spaces(&mut comment);
                 self.word("//");
-                self.word(comment);
+                self.plain(comment);
                 self.hardbreak();
                 return;
             } else if can_be_block_comment(&comment) && !comment.starts_with(&['*', '!'][..]) {
                 trim_interior_trailing_spaces(&mut comment);
                 self.word("/*");
-                self.word(comment);
+                self.plain(comment);
                 self.word("*/");
                 self.hardbreak();
                 return;
@@ -225,7 +226,7 @@ fn value_of_attribute(requested: &str, attr: &Attribute) -> Option<String> {
 
 pub fn has_outer(attrs: &[Attribute]) -> bool {
     for attr in attrs {
-        if let AttrStyle::Outer = attr.style {
+        if let (AttrStyle::Outer, None) = (attr.style, Tag::of(attr)) {
             return true;
         }
     }
@@ -1,4 +1,5 @@
 use crate::pretty::please::algorithm::{self, BeginToken, BreakToken, Breaks, Printer};
+use crate::pretty::please::highlight::Category;
 use std::borrow::Cow;
 
 impl Printer {
@@ -20,9 +21,35 @@ impl Printer {
         self.scan_end();
     }
 
+    /// Writes punctuation, such as brackets and operators
     pub fn word<S: Into<Cow<'static, str>>>(&mut self, wrd: S) {
         let s = wrd.into();
-        self.scan_string(s);
+        let category = (!s.trim().is_empty()).then_some(Category::Punctuation);
+        self.scan_word(s, category);
+    }
+
+    /// Writes a keyword, such as `let` or `mut`
+    pub fn keyword<S: Into<Cow<'static, str>>>(&mut self, wrd: S) {
+        self.scan_word(wrd.into(), Some(Category::Keyword));
+    }
+
+    /// Writes text that is not highlighted, such as identifiers and comments
+    pub fn plain<S: Into<Cow<'static, str>>>(&mut self, wrd: S) {
+        self.scan_word(wrd.into(), None);
+    }
+
+    pub fn word_as<S: Into<Cow<'static, str>>>(&mut self, category: Category, wrd: S) {
+        self.scan_word(wrd.into(), Some(category));
+    }
+
+    fn scan_word(&mut self, s: Cow<'static, str>, category: Option<Category>) {
+        // everything but the punctuation within a map key is highlighted as the key
+        let category = match category {
+            Some(Category::Punctuation) => category,
+            _ if self.map_key && !s.trim().is_empty() => Some(Category::MapKey),
+            _ => category,
+        };
+        self.scan_string(s, category);
     }
 
     fn spaces(&mut self, n: usize) {
@@ -58,19 +58,20 @@ impl Printer {
 
     pub fn visibility(&mut self, vis: &Visibility) {
         match vis {
-            Visibility::Public(_) => self.word("pub "),
+            Visibility::Public(_) => self.keyword("pub "),
             Visibility::Restricted(vis) => self.vis_restricted(vis),
             Visibility::Inherited => {}
         }
     }
 
     fn vis_restricted(&mut self, vis: &VisRestricted) {
-        self.word("pub(");
+        self.keyword("pub");
+        self.word("(");
         let omit_in = vis.path.get_ident().map_or(false, |ident| {
             matches!(ident.to_string().as_str(), "self" | "super" | "crate")
         });
         if !omit_in {
-            self.word("in ");
+            self.keyword("in ");
         }
         self.path(&vis.path, PathKind::Simple);
         self.word(") ");
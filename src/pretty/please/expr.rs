@@ -1,9 +1,12 @@
 use crate::pretty::please::algorithm::{BreakToken, Printer};
 use crate::pretty::please::attr;
+use crate::pretty::please::highlight::Category;
 use crate::pretty::please::iter::IterDelimited;
 use crate::pretty::please::path::PathKind;
+use crate::pretty::please::source::Source;
 use crate::pretty::please::stmt;
 use crate::pretty::please::INDENT;
+use crate::tag::Tag;
 use proc_macro2::TokenStream;
 use syn::punctuated::Punctuated;
 use syn::{
@@ -125,7 +128,10 @@ impl Printer {
     fn expr_assign(&mut self, expr: &ExprAssign) {
         self.outer_attrs(&expr.attrs);
         self.ibox(0);
+        let map_key = Tag::MapEntry.is_on(&expr.attrs);
+        let outer = std::mem::replace(&mut self.map_key, map_key);
         self.expr(&expr.left);
+        self.map_key = outer;
         self.word(" = ");
         self.expr(&expr.right);
         self.end();
@@ -133,9 +139,9 @@ impl Printer {
 
     fn expr_async(&mut self, expr: &ExprAsync) {
         self.outer_attrs(&expr.attrs);
-        self.word("async ");
+        self.keyword("async ");
         if expr.capture.is_some() {
-            self.word("move ");
+            self.keyword("move ");
         }
         self.cbox(INDENT);
         self.small_block(&expr.block, &expr.attrs);
@@ -152,7 +158,8 @@ impl Printer {
     fn subexpr_await(&mut self, expr: &ExprAwait, beginning_of_line: bool) {
         self.subexpr(&expr.base, beginning_of_line);
         self.zerobreak_unless_short_ident(beginning_of_line, &expr.base);
-        self.word(".await");
+        self.word(".");
+        self.keyword("await");
     }
 
     fn expr_binary(&mut self, expr: &ExprBinary) {
@@ -180,7 +187,7 @@ impl Printer {
 
     fn expr_break(&mut self, expr: &ExprBreak) {
         self.outer_attrs(&expr.attrs);
-        self.word("break");
+        self.keyword("break");
         if let Some(lifetime) = &expr.label {
             self.nbsp();
             self.lifetime(lifetime);
@@ -213,7 +220,7 @@ impl Printer {
         self.expr(&expr.expr);
         self.end();
         self.space();
-        self.word("as ");
+        self.keyword("as ");
         self.ty(&expr.ty);
         self.end();
     }
@@ -225,16 +232,16 @@ impl Printer {
             self.bound_lifetimes(bound_lifetimes);
         }
         if expr.constness.is_some() {
-            self.word("const ");
+            self.keyword("const ");
         }
         if expr.movability.is_some() {
-            self.word("static ");
+            self.keyword("static ");
         }
         if expr.asyncness.is_some() {
-            self.word("async ");
+            self.keyword("async ");
         }
         if expr.capture.is_some() {
-            self.word("move ");
+            self.keyword("move ");
         }
         self.cbox(INDENT);
         self.word("|");
@@ -299,7 +306,7 @@ impl Printer {
 
     pub fn expr_const(&mut self, expr: &ExprConst) {
         self.outer_attrs(&expr.attrs);
-        self.word("const ");
+        self.keyword("const ");
         self.cbox(INDENT);
         self.small_block(&expr.block, &expr.attrs);
         self.end();
@@ -307,7 +314,7 @@ impl Printer {
 
     fn expr_continue(&mut self, expr: &ExprContinue) {
         self.outer_attrs(&expr.attrs);
-        self.word("continue");
+        self.keyword("continue");
         if let Some(lifetime) = &expr.label {
             self.nbsp();
             self.lifetime(lifetime);
@@ -334,9 +341,9 @@ impl Printer {
         if let Some(label) = &expr.label {
             self.label(label);
         }
-        self.word("for ");
+        self.keyword("for ");
         self.pat(&expr.pat);
-        self.word(" in ");
+        self.keyword(" in ");
         self.neverbreak();
         self.wrap_exterior_struct(&expr.expr);
         self.word("{");
@@ -361,7 +368,7 @@ impl Printer {
     fn expr_if(&mut self, expr: &ExprIf) {
         self.outer_attrs(&expr.attrs);
         self.cbox(INDENT);
-        self.word("if ");
+        self.keyword("if ");
         self.cbox(-INDENT);
         self.wrap_exterior_struct(&expr.cond);
         self.end();
@@ -369,10 +376,10 @@ impl Printer {
             let mut else_branch = &**else_branch;
             self.small_block(&expr.then_branch, &[]);
             loop {
-                self.word(" else ");
+                self.keyword(" else ");
                 match else_branch {
                     Expr::If(expr) => {
-                        self.word("if ");
+                        self.keyword("if ");
                         self.cbox(-INDENT);
                         self.wrap_exterior_struct(&expr.cond);
                         self.end();
@@ -431,13 +438,13 @@ impl Printer {
 
     fn expr_infer(&mut self, expr: &ExprInfer) {
         self.outer_attrs(&expr.attrs);
-        self.word("_");
+        self.plain("_");
     }
 
     fn expr_let(&mut self, expr: &ExprLet) {
         self.outer_attrs(&expr.attrs);
         self.ibox(INDENT);
-        self.word("let ");
+        self.keyword("let ");
         self.ibox(-INDENT);
         self.pat(&expr.pat);
         self.end();
@@ -464,7 +471,8 @@ impl Printer {
         if let Some(label) = &expr.label {
             self.label(label);
         }
-        self.word("loop {");
+        self.keyword("loop ");
+        self.word("{");
         self.cbox(INDENT);
         self.hardbreak_if_nonempty();
         self.inner_attrs(&expr.attrs);
@@ -485,7 +493,7 @@ impl Printer {
     fn expr_match(&mut self, expr: &ExprMatch) {
         self.outer_attrs(&expr.attrs);
         self.ibox(0);
-        self.word("match ");
+        self.keyword("match ");
         self.wrap_exterior_struct(&expr.expr);
         self.word("{");
         self.neverbreak();
@@ -539,7 +547,14 @@ impl Printer {
 
     pub fn expr_path(&mut self, expr: &ExprPath) {
         self.outer_attrs(&expr.attrs);
+        let variant = Tag::Variant.is_on(&expr.attrs);
+        if variant {
+            self.ident_category = Some(Category::Variant);
+        }
         self.qpath(&expr.qself, &expr.path, PathKind::Expr);
+        if variant {
+            self.ident_category = None;
+        }
     }
 
     pub fn expr_range(&mut self, expr: &ExprRange) {
@@ -560,7 +575,7 @@ impl Printer {
         self.outer_attrs(&expr.attrs);
         self.word("&");
         if expr.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         }
         self.expr(&expr.expr);
     }
@@ -576,7 +591,7 @@ impl Printer {
 
     fn expr_return(&mut self, expr: &ExprReturn) {
         self.outer_attrs(&expr.attrs);
-        self.word("return");
+        self.keyword("return");
         if let Some(value) = &expr.expr {
             self.nbsp();
             self.expr(value);
@@ -587,12 +602,15 @@ impl Printer {
         self.outer_attrs(&expr.attrs);
         self.cbox(INDENT);
         self.ibox(-INDENT);
+        let tagged = Tag::Struct.is_on(&expr.attrs);
+        self.ident_category = tagged.then_some(Category::Type);
         self.qpath(&expr.qself, &expr.path, PathKind::Expr);
+        self.ident_category = None;
         self.end();
         self.word(" {");
         self.space_if_nonempty();
         for field_value in expr.fields.iter().delimited() {
-            self.field_value(&field_value);
+            self.field_value(&field_value, tagged);
             self.trailing_comma_or_space(field_value.is_last && expr.rest.is_none());
         }
         if let Some(rest) = &expr.rest {
@@ -605,6 +623,27 @@ impl Printer {
         self.word("}");
     }
 
+    /// Prints values labeled with the expressions they came from,
+    /// laid out like the fields of a struct, eg `{ a: 1, b + c: 2 }`
+    pub fn labeled(&mut self, fields: &[(Source, Expr)]) {
+        self.cbox(INDENT);
+        self.word("{");
+        self.space_if_nonempty();
+        for (i, (label, value)) in fields.iter().enumerate() {
+            self.ibox(0);
+            self.source(label);
+            self.end();
+            self.word(": ");
+            self.ibox(0);
+            self.expr(value);
+            self.end();
+            self.trailing_comma_or_space(i + 1 == fields.len());
+        }
+        self.offset(-INDENT);
+        self.end_with_max_width(34);
+        self.word("}");
+    }
+
     fn expr_try(&mut self, expr: &ExprTry, beginning_of_line: bool) {
         self.outer_attrs(&expr.attrs);
         self.expr_beginning_of_line(&expr.expr, beginning_of_line);
@@ -618,7 +657,7 @@ impl Printer {
 
     fn expr_try_block(&mut self, expr: &ExprTryBlock) {
         self.outer_attrs(&expr.attrs);
-        self.word("try ");
+        self.keyword("try ");
         self.cbox(INDENT);
         self.small_block(&expr.block, &expr.attrs);
         self.end();
@@ -651,7 +690,7 @@ impl Printer {
 
     fn expr_unsafe(&mut self, expr: &ExprUnsafe) {
         self.outer_attrs(&expr.attrs);
-        self.word("unsafe ");
+        self.keyword("unsafe ");
         self.cbox(INDENT);
         self.small_block(&expr.block, &expr.attrs);
         self.end();
@@ -737,7 +776,8 @@ impl Printer {
             }
             ExprVerbatim::Builtin(expr) => {
                 self.outer_attrs(&expr.attrs);
-                self.word("builtin # ");
+                self.keyword("builtin ");
+                self.word("# ");
                 self.ident(&expr.name);
                 self.word("(");
                 if !expr.args.is_empty() {
@@ -754,8 +794,9 @@ impl Printer {
             }
             ExprVerbatim::RawReference(expr) => {
                 self.outer_attrs(&expr.attrs);
-                self.word("&raw ");
-                self.word(if expr.mutable { "mut " } else { "const " });
+                self.word("&");
+                self.keyword("raw ");
+                self.keyword(if expr.mutable { "mut " } else { "const " });
                 self.expr(&expr.expr);
             }
         }
@@ -766,7 +807,7 @@ impl Printer {
         if let Some(label) = &expr.label {
             self.label(label);
         }
-        self.word("while ");
+        self.keyword("while ");
         self.wrap_exterior_struct(&expr.cond);
         self.word("{");
         self.neverbreak();
@@ -783,7 +824,7 @@ impl Printer {
 
     fn expr_yield(&mut self, expr: &ExprYield) {
         self.outer_attrs(&expr.attrs);
-        self.word("yield");
+        self.keyword("yield");
         if let Some(value) = &expr.expr {
             self.nbsp();
             self.expr(value);
@@ -795,9 +836,11 @@ impl Printer {
         self.word(": ");
     }
 
-    fn field_value(&mut self, field_value: &FieldValue) {
+    fn field_value(&mut self, field_value: &FieldValue, tagged: bool) {
         self.outer_attrs(&field_value.attrs);
+        self.ident_category = tagged.then_some(Category::Field);
         self.member(&field_value.member);
+        self.ident_category = None;
         if field_value.colon_token.is_some() {
             self.word(": ");
             self.ibox(0);
@@ -811,7 +854,7 @@ impl Printer {
         self.ibox(0);
         self.pat(&arm.pat);
         if let Some((_if_token, guard)) = &arm.guard {
-            self.word(" if ");
+            self.keyword(" if ");
             self.expr(guard);
         }
         self.word(" =>");
@@ -929,7 +972,10 @@ impl Printer {
     }
 
     fn index(&mut self, member: &Index) {
-        self.word(member.index.to_string());
+        match self.ident_category {
+            Some(category) => self.word_as(category, member.index.to_string()),
+            None => self.plain(member.index.to_string()),
+        }
     }
 
     fn binary_operator(&mut self, op: &BinOp) {
@@ -1148,7 +1194,7 @@ fn needs_newline_if_wrap(expr: &Expr) -> bool {
 
 fn is_short_ident(expr: &Expr) -> bool {
     if let Expr::Path(expr) = expr {
-        return expr.attrs.is_empty()
+        return !attr::has_outer(&expr.attrs)
             && expr.qself.is_none()
             && expr
                 .path
@@ -57,7 +57,8 @@ impl Printer {
     }
 
     pub fn bound_lifetimes(&mut self, bound_lifetimes: &BoundLifetimes) {
-        self.word("for<");
+        self.keyword("for");
+        self.word("<");
         for param in bound_lifetimes.lifetimes.iter().delimited() {
             self.generic_param(&param);
             if !param.is_last {
@@ -119,7 +120,8 @@ impl Printer {
             self.word("(");
         }
         if tilde_const {
-            self.word("~const ");
+            self.word("~");
+            self.keyword("const ");
         }
         self.trait_bound_modifier(trait_bound.modifier);
         if let Some(bound_lifetimes) = &trait_bound.lifetimes {
@@ -200,7 +202,7 @@ impl Printer {
 
     fn const_param(&mut self, const_param: &ConstParam) {
         self.outer_attrs(&const_param.attrs);
-        self.word("const ");
+        self.keyword("const ");
         self.ident(&const_param.ident);
         self.word(": ");
         self.ty(&const_param.ty);
@@ -254,7 +256,7 @@ impl Printer {
         if hardbreaks {
             self.hardbreak();
             self.offset(-INDENT);
-            self.word("where");
+            self.keyword("where");
             self.hardbreak();
             for predicate in where_clause.predicates.iter().delimited() {
                 self.where_predicate(&predicate);
@@ -271,7 +273,7 @@ impl Printer {
         } else {
             self.space();
             self.offset(-INDENT);
-            self.word("where");
+            self.keyword("where");
             self.space();
             for predicate in where_clause.predicates.iter().delimited() {
                 self.where_predicate(&predicate);
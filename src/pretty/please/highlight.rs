@@ -0,0 +1,47 @@
+use std::ops::Range;
+
+/// The semantic category of a printed token, used for syntax highlighting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Keyword,
+    Punctuation,
+    // the name of a struct, from `DebugStruct`
+    Type,
+    // the name of a tuple struct or unit value, from `DebugTupleStruct` or `debug_ident`
+    Variant,
+    // a field name, from `DebugStruct`
+    Field,
+    // anything within a key, from `DebugMap`
+    MapKey,
+    String,
+    Char,
+    Number,
+    Bool,
+}
+
+/// Printed text, along with the categories of its tokens
+#[cfg_attr(not(feature = "colors"), allow(dead_code))]
+pub struct Highlighted {
+    pub text: String,
+    pub spans: Vec<(Range<usize>, Category)>,
+}
+
+impl Category {
+    /// The scope name of this category, following the
+    /// [sublime text naming conventions](https://www.sublimetext.com/docs/scope_naming.html)
+    #[cfg_attr(not(feature = "colors"), allow(dead_code))]
+    pub fn scope(self) -> &'static str {
+        match self {
+            Category::Keyword => "keyword",
+            Category::Punctuation => "punctuation",
+            Category::Type => "storage.type",
+            Category::Variant => "entity.name.variant",
+            Category::Field => "variable.other.member",
+            Category::MapKey => "meta.mapping.key",
+            Category::String => "string.quoted",
+            Category::Char => "constant.character",
+            Category::Number => "constant.numeric",
+            Category::Bool => "constant.language",
+        }
+    }
+}
@@ -40,7 +40,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(0);
         self.visibility(&item.vis);
-        self.word("const ");
+        self.keyword("const ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         self.word(": ");
@@ -57,7 +57,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         self.visibility(&item.vis);
-        self.word("enum ");
+        self.keyword("enum ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         self.where_clause_for_body(&item.generics.where_clause);
@@ -77,10 +77,10 @@ impl Printer {
     fn item_extern_crate(&mut self, item: &ItemExternCrate) {
         self.outer_attrs(&item.attrs);
         self.visibility(&item.vis);
-        self.word("extern crate ");
+        self.keyword("extern crate ");
         self.ident(&item.ident);
         if let Some((_as_token, rename)) = &item.rename {
-            self.word(" as ");
+            self.keyword(" as ");
             self.ident(rename);
         }
         self.word(";");
@@ -109,7 +109,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         if item.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
         self.abi(&item.abi);
         self.word("{");
@@ -130,12 +130,12 @@ impl Printer {
         self.ibox(-INDENT);
         self.cbox(INDENT);
         if item.defaultness.is_some() {
-            self.word("default ");
+            self.keyword("default ");
         }
         if item.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
-        self.word("impl");
+        self.keyword("impl");
         self.generics(&item.generics);
         self.end();
         self.nbsp();
@@ -145,7 +145,7 @@ impl Printer {
             }
             self.path(path, PathKind::Type);
             self.space();
-            self.word("for ");
+            self.keyword("for ");
         }
         self.ty(&item.self_ty);
         self.end();
@@ -174,9 +174,9 @@ impl Printer {
         self.cbox(INDENT);
         self.visibility(&item.vis);
         if item.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
-        self.word("mod ");
+        self.keyword("mod ");
         self.ident(&item.ident);
         if let Some((_brace, items)) = &item.content {
             self.word(" {");
@@ -199,7 +199,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(0);
         self.visibility(&item.vis);
-        self.word("static ");
+        self.keyword("static ");
         self.static_mutability(&item.mutability);
         self.ident(&item.ident);
         self.word(": ");
@@ -216,7 +216,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         self.visibility(&item.vis);
-        self.word("struct ");
+        self.keyword("struct ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         match &item.fields {
@@ -251,12 +251,12 @@ impl Printer {
         self.cbox(INDENT);
         self.visibility(&item.vis);
         if item.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
         if item.auto_token.is_some() {
-            self.word("auto ");
+            self.keyword("auto ");
         }
-        self.word("trait ");
+        self.keyword("trait ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         for supertrait in item.supertraits.iter().delimited() {
@@ -284,7 +284,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         self.visibility(&item.vis);
-        self.word("trait ");
+        self.keyword("trait ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         self.word(" = ");
@@ -305,7 +305,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         self.visibility(&item.vis);
-        self.word("type ");
+        self.keyword("type ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         self.where_clause_oneline(&item.generics.where_clause);
@@ -323,7 +323,7 @@ impl Printer {
         self.outer_attrs(&item.attrs);
         self.cbox(INDENT);
         self.visibility(&item.vis);
-        self.word("union ");
+        self.keyword("union ");
         self.ident(&item.ident);
         self.generics(&item.generics);
         self.where_clause_for_body(&item.generics.where_clause);
@@ -343,7 +343,7 @@ impl Printer {
     fn item_use(&mut self, item: &ItemUse) {
         self.outer_attrs(&item.attrs);
         self.visibility(&item.vis);
-        self.word("use ");
+        self.keyword("use ");
         if item.leading_colon.is_some() {
             self.word("::");
         }
@@ -593,19 +593,19 @@ impl Printer {
                 self.cbox(INDENT);
                 self.visibility(&item.vis);
                 if item.defaultness {
-                    self.word("default ");
+                    self.keyword("default ");
                 }
                 if item.unsafety {
-                    self.word("unsafe ");
+                    self.keyword("unsafe ");
                 }
-                self.word("impl");
+                self.keyword("impl");
                 self.generics(&item.generics);
                 self.end();
                 self.nbsp();
                 match item.constness {
                     ImplConstness::None => {}
-                    ImplConstness::MaybeConst => self.word("?const "),
-                    ImplConstness::Const => self.word("const "),
+                    ImplConstness::MaybeConst => self.keyword("?const "),
+                    ImplConstness::Const => self.keyword("const "),
                 }
                 if item.negative_impl {
                     self.word("!");
@@ -613,7 +613,7 @@ impl Printer {
                 if let Some(trait_) = &item.trait_ {
                     self.ty(trait_);
                     self.space();
-                    self.word("for ");
+                    self.keyword("for ");
                 }
                 self.ty(&item.self_ty);
                 self.end();
@@ -632,7 +632,7 @@ impl Printer {
             ItemVerbatim::Macro2(item) => {
                 self.outer_attrs(&item.attrs);
                 self.visibility(&item.vis);
-                self.word("macro ");
+                self.keyword("macro ");
                 self.ident(&item.ident);
                 if let Some(args) = &item.args {
                     self.word("(");
@@ -670,7 +670,7 @@ impl Printer {
             ItemVerbatim::UseBrace(item) => {
                 self.outer_attrs(&item.attrs);
                 self.visibility(&item.vis);
-                self.word("use ");
+                self.keyword("use ");
                 if item.trees.len() == 1 {
                     self.word("::");
                     self.use_tree(&item.trees[0].inner);
@@ -731,7 +731,7 @@ impl Printer {
 
     fn use_rename(&mut self, use_rename: &UseRename) {
         self.ident(&use_rename.ident);
-        self.word(" as ");
+        self.keyword(" as ");
         self.ident(&use_rename.rename);
     }
 
@@ -799,7 +799,7 @@ impl Printer {
         self.outer_attrs(&foreign_item.attrs);
         self.cbox(0);
         self.visibility(&foreign_item.vis);
-        self.word("static ");
+        self.keyword("static ");
         self.static_mutability(&foreign_item.mutability);
         self.ident(&foreign_item.ident);
         self.word(": ");
@@ -813,7 +813,7 @@ impl Printer {
         self.outer_attrs(&foreign_item.attrs);
         self.cbox(0);
         self.visibility(&foreign_item.vis);
-        self.word("type ");
+        self.keyword("type ");
         self.ident(&foreign_item.ident);
         self.generics(&foreign_item.generics);
         self.word(";");
@@ -930,7 +930,7 @@ impl Printer {
     fn trait_item_const(&mut self, trait_item: &TraitItemConst) {
         self.outer_attrs(&trait_item.attrs);
         self.cbox(0);
-        self.word("const ");
+        self.keyword("const ");
         self.ident(&trait_item.ident);
         self.generics(&trait_item.generics);
         self.word(": ");
@@ -970,7 +970,7 @@ impl Printer {
     fn trait_item_type(&mut self, trait_item: &TraitItemType) {
         self.outer_attrs(&trait_item.attrs);
         self.cbox(INDENT);
-        self.word("type ");
+        self.keyword("type ");
         self.ident(&trait_item.ident);
         self.generics(&trait_item.generics);
         for bound in trait_item.bounds.iter().delimited() {
@@ -1091,7 +1091,7 @@ impl Printer {
                 self.outer_attrs(&trait_item.attrs);
                 self.visibility(&trait_item.vis);
                 if trait_item.defaultness {
-                    self.word("default ");
+                    self.keyword("default ");
                 }
                 self.trait_item(&trait_item.trait_item);
             }
@@ -1115,9 +1115,9 @@ impl Printer {
         self.cbox(0);
         self.visibility(&impl_item.vis);
         if impl_item.defaultness.is_some() {
-            self.word("default ");
+            self.keyword("default ");
         }
-        self.word("const ");
+        self.keyword("const ");
         self.ident(&impl_item.ident);
         self.generics(&impl_item.generics);
         self.word(": ");
@@ -1135,7 +1135,7 @@ impl Printer {
         self.cbox(INDENT);
         self.visibility(&impl_item.vis);
         if impl_item.defaultness.is_some() {
-            self.word("default ");
+            self.keyword("default ");
         }
         self.signature(&impl_item.sig);
         self.where_clause_for_body(&impl_item.sig.generics.where_clause);
@@ -1156,9 +1156,9 @@ impl Printer {
         self.cbox(INDENT);
         self.visibility(&impl_item.vis);
         if impl_item.defaultness.is_some() {
-            self.word("default ");
+            self.keyword("default ");
         }
-        self.word("type ");
+        self.keyword("type ");
         self.ident(&impl_item.ident);
         self.generics(&impl_item.generics);
         self.word(" = ");
@@ -1267,18 +1267,18 @@ impl Printer {
 
     fn signature(&mut self, signature: &Signature) {
         if signature.constness.is_some() {
-            self.word("const ");
+            self.keyword("const ");
         }
         if signature.asyncness.is_some() {
-            self.word("async ");
+            self.keyword("async ");
         }
         if signature.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
         if let Some(abi) = &signature.abi {
             self.abi(abi);
         }
-        self.word("fn ");
+        self.keyword("fn ");
         self.ident(&signature.ident);
         self.generics(&signature.generics);
         self.word("(");
@@ -1319,9 +1319,9 @@ impl Printer {
             }
         }
         if receiver.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         }
-        self.word("self");
+        self.keyword("self");
         if receiver.colon_token.is_some() {
             self.word(": ");
             self.ty(&receiver.ty);
@@ -1355,7 +1355,7 @@ impl Printer {
 
     fn static_mutability(&mut self, mutability: &StaticMutability) {
         match mutability {
-            StaticMutability::Mut(_) => self.word("mut "),
+            StaticMutability::Mut(_) => self.keyword("mut "),
             StaticMutability::None => {}
             #[cfg_attr(all(test, exhaustive), deny(non_exhaustive_omitted_patterns))]
             _ => unimplemented!("unknown StaticMutability"),
@@ -1583,9 +1583,9 @@ mod verbatim {
             self.cbox(0);
             self.visibility(&item.vis);
             if item.defaultness {
-                self.word("default ");
+                self.keyword("default ");
             }
-            self.word("const ");
+            self.keyword("const ");
             self.ident(&item.ident);
             self.word(": ");
             self.ty(&item.ty);
@@ -1599,7 +1599,7 @@ mod verbatim {
             self.cbox(INDENT);
             self.visibility(&item.vis);
             if item.defaultness {
-                self.word("default ");
+                self.keyword("default ");
             }
             self.signature(&item.sig);
             if let Some(body) = &item.body {
@@ -1624,7 +1624,7 @@ mod verbatim {
             self.outer_attrs(&item.attrs);
             self.cbox(0);
             self.visibility(&item.vis);
-            self.word("static ");
+            self.keyword("static ");
             self.static_mutability(&item.mutability);
             self.ident(&item.ident);
             if let Some(ty) = &item.ty {
@@ -1646,9 +1646,9 @@ mod verbatim {
             self.cbox(INDENT);
             self.visibility(&item.vis);
             if item.defaultness {
-                self.word("default ");
+                self.keyword("default ");
             }
-            self.word("type ");
+            self.keyword("type ");
             self.ident(&item.ident);
             self.generics(&item.generics);
             for bound in item.bounds.iter().delimited() {
@@ -1,4 +1,5 @@
 use crate::pretty::please::algorithm::Printer;
+use crate::pretty::please::highlight::Category;
 use proc_macro2::Literal;
 use syn::{Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr};
 
@@ -19,34 +20,34 @@ impl Printer {
     }
 
     pub fn lit_str(&mut self, lit: &LitStr) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::String, lit.token().to_string());
     }
 
     fn lit_byte_str(&mut self, lit: &LitByteStr) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::String, lit.token().to_string());
     }
 
     fn lit_byte(&mut self, lit: &LitByte) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::Char, lit.token().to_string());
     }
 
     fn lit_char(&mut self, lit: &LitChar) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::Char, lit.token().to_string());
     }
 
     fn lit_int(&mut self, lit: &LitInt) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::Number, lit.token().to_string());
     }
 
     fn lit_float(&mut self, lit: &LitFloat) {
-        self.word(lit.token().to_string());
+        self.word_as(Category::Number, lit.token().to_string());
     }
 
     fn lit_bool(&mut self, lit: &LitBool) {
-        self.word(if lit.value { "true" } else { "false" });
+        self.word_as(Category::Bool, if lit.value { "true" } else { "false" });
     }
 
     fn lit_verbatim(&mut self, token: &Literal) {
-        self.word(token.to_string());
+        self.plain(token.to_string());
     }
 }
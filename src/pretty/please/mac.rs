@@ -59,7 +59,7 @@ impl Printer {
 
         use State::*;
 
-        self.word("macro_rules! ");
+        self.keyword("macro_rules! ");
         self.ident(name);
         self.word(" {");
         self.cbox(INDENT);
@@ -576,7 +576,7 @@ mod standard_library {
                     self.pat(&matches.pattern);
                     if let Some(guard) = &matches.guard {
                         self.space();
-                        self.word("if ");
+                        self.keyword("if ");
                         self.expr(guard);
                     }
                     self.zerobreak();
@@ -592,7 +592,7 @@ mod standard_library {
                         self.outer_attrs(&item.attrs);
                         self.cbox(0);
                         self.visibility(&item.vis);
-                        self.word("static ");
+                        self.keyword("static ");
                         self.ident(&item.name);
                         self.word(": ");
                         self.ty(&item.ty);
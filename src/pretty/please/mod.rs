@@ -141,6 +141,7 @@ mod convenience;
 mod data;
 mod expr;
 mod generics;
+mod highlight;
 mod item;
 mod iter;
 mod lifetime;
@@ -149,6 +150,7 @@ mod mac;
 mod pat;
 mod path;
 mod ring;
+mod source;
 mod stmt;
 mod token;
 mod ty;
@@ -156,11 +158,31 @@ mod ty;
 use crate::pretty::please::algorithm::Printer;
 use syn::Expr;
 
+pub use crate::pretty::please::highlight::Highlighted;
+pub use crate::pretty::please::source::Source;
+
 // Number of spaces increment at each level of block indentation.
 const INDENT: isize = 4;
 
 pub fn unparse(expr: &Expr, max_width: Option<usize>) -> String {
+    unparse_highlighted(expr, max_width).text
+}
+
+pub fn unparse_highlighted(expr: &Expr, max_width: Option<usize>) -> Highlighted {
     let mut p = Printer::new(max_width.unwrap_or(89) as isize);
     p.expr(expr);
     p.eof()
 }
+
+pub fn unparse_source(source: &Source, max_width: Option<usize>) -> Highlighted {
+    let mut p = Printer::new(max_width.unwrap_or(89) as isize);
+    p.source(source);
+    p.eof()
+}
+
+/// Prints values labeled with the expressions they came from, eg `{ a: 1, b + c: 2 }`
+pub fn unparse_labeled(fields: &[(Source, Expr)], max_width: Option<usize>) -> Highlighted {
+    let mut p = Printer::new(max_width.unwrap_or(89) as isize);
+    p.labeled(fields);
+    p.eof()
+}
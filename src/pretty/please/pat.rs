@@ -36,10 +36,10 @@ impl Printer {
     fn pat_ident(&mut self, pat: &PatIdent) {
         self.outer_attrs(&pat.attrs);
         if pat.by_ref.is_some() {
-            self.word("ref ");
+            self.keyword("ref ");
         }
         if pat.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         }
         self.ident(&pat.ident);
         if let Some((_at_token, subpat)) = &pat.subpat {
@@ -86,7 +86,7 @@ impl Printer {
         self.outer_attrs(&pat.attrs);
         self.word("&");
         if pat.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         }
         self.pat(&pat.pat);
     }
@@ -225,11 +225,11 @@ impl Printer {
                 self.word("...");
             }
             PatVerbatim::Box(pat) => {
-                self.word("box ");
+                self.keyword("box ");
                 self.pat(&pat);
             }
             PatVerbatim::Const(pat) => {
-                self.word("const ");
+                self.keyword("const ");
                 self.cbox(INDENT);
                 self.small_block(&pat.block, &pat.attrs);
                 self.end();
@@ -239,7 +239,7 @@ impl Printer {
 
     fn pat_wild(&mut self, pat: &PatWild) {
         self.outer_attrs(&pat.attrs);
-        self.word("_");
+        self.plain("_");
     }
 
     fn field_pat(&mut self, field_pat: &FieldPat) {
@@ -183,7 +183,7 @@ impl Printer {
 
         let mut segments = path.segments.iter();
         if qself.position > 0 {
-            self.word(" as ");
+            self.keyword(" as ");
             for segment in segments.by_ref().take(qself.position).delimited() {
                 if !segment.is_first || path.leading_colon.is_some() {
                     self.word("::");
@@ -0,0 +1,38 @@
+use crate::pretty::please::algorithm::Printer;
+use syn::{parse::Parser, Block, Expr, ExprBlock};
+
+/// The source code of a macro argument
+pub enum Source {
+    Expr(Expr),
+    /// Source that is not valid syntax, which is printed as is
+    Raw(String),
+}
+
+impl Source {
+    /// Parses the source as an expression, or else as the statements of a block
+    pub fn parse(source: &str) -> Self {
+        if let Ok(expr) = syn::parse_str(source) {
+            return Source::Expr(expr);
+        }
+        match Block::parse_within.parse_str(source) {
+            Ok(stmts) => Source::Expr(Expr::Block(ExprBlock {
+                attrs: vec![],
+                label: None,
+                block: Block {
+                    brace_token: syn::token::Brace::default(),
+                    stmts,
+                },
+            })),
+            Err(_) => Source::Raw(source.trim().to_owned()),
+        }
+    }
+}
+
+impl Printer {
+    pub fn source(&mut self, source: &Source) {
+        match source {
+            Source::Expr(expr) => self.expr(expr),
+            Source::Raw(raw) => self.scan_string(raw.clone().into(), None),
+        }
+    }
+}
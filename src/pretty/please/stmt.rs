@@ -8,7 +8,7 @@ impl Printer {
             Stmt::Local(local) => {
                 self.outer_attrs(&local.attrs);
                 self.ibox(0);
-                self.word("let ");
+                self.keyword("let ");
                 self.pat(&local.pat);
                 if let Some(local_init) = &local.init {
                     self.word(" = ");
@@ -16,7 +16,7 @@ impl Printer {
                     self.expr(&local_init.expr);
                     if let Some((_else, diverge)) = &local_init.diverge {
                         self.space();
-                        self.word("else ");
+                        self.keyword("else ");
                         self.end();
                         self.neverbreak();
                         if let Expr::Block(expr) = diverge.as_ref() {
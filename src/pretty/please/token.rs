@@ -1,5 +1,6 @@
 use crate::pretty::please::algorithm::Printer;
 use proc_macro2::{Delimiter, Ident, Literal, Spacing, TokenStream, TokenTree};
+use syn::Lit;
 
 impl Printer {
     pub fn single_token(&mut self, token: Token, group_contents: fn(&mut Self, TokenStream)) {
@@ -31,7 +32,10 @@ impl Printer {
     }
 
     pub fn ident(&mut self, ident: &Ident) {
-        self.word(ident.to_string());
+        match self.ident_category {
+            Some(category) => self.word_as(category, ident.to_string()),
+            None => self.plain(ident.to_string()),
+        }
     }
 
     pub fn token_punct(&mut self, ch: char) {
@@ -39,7 +43,7 @@ impl Printer {
     }
 
     pub fn token_literal(&mut self, literal: &Literal) {
-        self.word(literal.to_string());
+        self.lit(&Lit::new(literal.clone()));
     }
 
     pub fn delimiter_open(&mut self, delimiter: Delimiter) {
@@ -45,12 +45,13 @@ impl Printer {
             self.bound_lifetimes(bound_lifetimes);
         }
         if ty.unsafety.is_some() {
-            self.word("unsafe ");
+            self.keyword("unsafe ");
         }
         if let Some(abi) = &ty.abi {
             self.abi(abi);
         }
-        self.word("fn(");
+        self.keyword("fn");
+        self.word("(");
         self.cbox(INDENT);
         self.zerobreak();
         for bare_fn_arg in ty.inputs.iter().delimited() {
@@ -72,7 +73,7 @@ impl Printer {
     }
 
     fn type_impl_trait(&mut self, ty: &TypeImplTrait) {
-        self.word("impl ");
+        self.keyword("impl ");
         for type_param_bound in ty.bounds.iter().delimited() {
             if !type_param_bound.is_first {
                 self.word(" + ");
@@ -83,7 +84,7 @@ impl Printer {
 
     fn type_infer(&mut self, ty: &TypeInfer) {
         let _ = ty;
-        self.word("_");
+        self.plain("_");
     }
 
     fn type_macro(&mut self, ty: &TypeMacro) {
@@ -109,9 +110,9 @@ impl Printer {
     fn type_ptr(&mut self, ty: &TypePtr) {
         self.word("*");
         if ty.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         } else {
-            self.word("const ");
+            self.keyword("const ");
         }
         self.ty(&ty.elem);
     }
@@ -123,7 +124,7 @@ impl Printer {
             self.nbsp();
         }
         if ty.mutability.is_some() {
-            self.word("mut ");
+            self.keyword("mut ");
         }
         self.ty(&ty.elem);
     }
@@ -135,7 +136,7 @@ impl Printer {
     }
 
     fn type_trait_object(&mut self, ty: &TypeTraitObject) {
-        self.word("dyn ");
+        self.keyword("dyn ");
         for type_param_bound in ty.bounds.iter().delimited() {
             if !type_param_bound.is_first {
                 self.word(" + ");
@@ -234,7 +235,8 @@ impl Printer {
                 self.word("...");
             }
             TypeVerbatim::DynStar(ty) => {
-                self.word("dyn* ");
+                self.keyword("dyn");
+                self.word("* ");
                 for type_param_bound in ty.bounds.iter().delimited() {
                     if !type_param_bound.is_first {
                         self.word(" + ");
@@ -243,7 +245,7 @@ impl Printer {
                 }
             }
             TypeVerbatim::MutSelf(bare_fn_arg) => {
-                self.word("mut self");
+                self.keyword("mut self");
                 if let Some(ty) = &bare_fn_arg.ty {
                     self.word(": ");
                     self.ty(ty);
@@ -285,7 +287,7 @@ impl Printer {
     }
 
     pub fn abi(&mut self, abi: &Abi) {
-        self.word("extern ");
+        self.keyword("extern ");
         if let Some(name) = &abi.name {
             self.lit_str(name);
             self.nbsp();
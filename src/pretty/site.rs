@@ -0,0 +1,83 @@
+//! State kept per call site by the conditional `pretty!` macros
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+/// Implementation detail for the `pretty_once!` macro
+pub struct Once(AtomicBool);
+
+impl Once {
+    #[must_use]
+    pub const fn new() -> Self {
+        Once(AtomicBool::new(false))
+    }
+
+    /// Whether this is the first call
+    pub fn first(&self) -> bool {
+        !self.0.swap(true, Ordering::Relaxed)
+    }
+}
+
+impl Default for Once {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation detail for the `pretty_every!` macro
+pub struct Every(Mutex<Option<Instant>>);
+
+impl Every {
+    #[must_use]
+    pub const fn new() -> Self {
+        Every(Mutex::new(None))
+    }
+
+    /// Whether at least `interval` has passed since this last returned `true`
+    pub fn ready(&self, interval: Duration) -> bool {
+        let mut last = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        if last.is_some_and(|last| now.duration_since(last) < interval) {
+            return false;
+        }
+        *last = Some(now);
+        true
+    }
+}
+
+impl Default for Every {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation detail for the `pretty_changed!` macro
+pub struct Changed(Mutex<Option<String>>);
+
+impl Changed {
+    #[must_use]
+    pub const fn new() -> Self {
+        Changed(Mutex::new(None))
+    }
+
+    /// Stores the rendering, returning whether it differs from the previous one
+    pub fn update(&self, rendered: &str) -> bool {
+        let mut last = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if last.as_deref() == Some(rendered) {
+            return false;
+        }
+        *last = Some(rendered.to_owned());
+        true
+    }
+}
+
+impl Default for Changed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{DebugWith, Formatter};
+
+/// A context that IDs can be looked up in, such as an arena or an interner.
+///
+/// Used by [`Resolved`] to print the value an ID points to, rather than the ID itself.
+///
+/// ```
+/// use dbg_pls::{pretty_with, DebugPls, Resolve};
+///
+/// #[derive(DebugPls, Clone, Copy)]
+/// struct NodeId(usize);
+///
+/// #[derive(DebugPls)]
+/// struct Node {
+///     name: &'static str,
+///     #[dbg_pls(resolve)]
+///     parent: NodeId,
+/// }
+///
+/// struct Arena(Vec<Node>);
+///
+/// impl Resolve<NodeId> for Arena {
+///     type Target = Node;
+///     fn resolve(&self, id: &NodeId) -> Option<&Node> {
+///         self.0.get(id.0)
+///     }
+/// }
+///
+/// let arena = Arena(vec![Node { name: "root", parent: NodeId(9) }]);
+/// let leaf = Node { name: "leaf", parent: NodeId(0) };
+/// let expected = r#"Node {
+///     name: "leaf",
+///     parent: Node {
+///         name: "root",
+///         parent: NodeId(9),
+///     },
+/// }"#;
+/// assert_eq!(format!("{}", pretty_with(&arena, &leaf)), expected);
+/// ```
+pub trait Resolve<Id: ?Sized>: Sized {
+    /// The type that the IDs resolve to
+    type Target: ?Sized + DebugWith<Self>;
+
+    /// Looks up the value for the given ID, if there is one
+    fn resolve(&self, id: &Id) -> Option<&Self::Target>;
+}
+
+/// Without a context, IDs are formatted as themselves
+impl<Id: ?Sized + DebugWith<()>> Resolve<Id> for () {
+    type Target = Id;
+    fn resolve(&self, _: &Id) -> Option<&Id> {
+        None
+    }
+}
+
+impl<T: DebugWith<Self>> Resolve<usize> for Vec<T> {
+    type Target = T;
+    fn resolve(&self, id: &usize) -> Option<&T> {
+        self.get(*id)
+    }
+}
+
+impl<K: Hash + Eq, V: DebugWith<Self>, S: BuildHasher> Resolve<K> for HashMap<K, V, S> {
+    type Target = V;
+    fn resolve(&self, id: &K) -> Option<&V> {
+        self.get(id)
+    }
+}
+
+impl<K: Ord, V: DebugWith<Self>> Resolve<K> for BTreeMap<K, V> {
+    type Target = V;
+    fn resolve(&self, id: &K) -> Option<&V> {
+        self.get(id)
+    }
+}
+
+/// Formats an ID as the value it resolves to in the [`Resolve`] context.
+///
+/// IDs that are missing from the context are formatted as themselves instead,
+/// as are IDs that resolve to a value that is already being formatted, to break cycles.
+///
+/// ```
+/// use dbg_pls::{pretty_with, Resolved};
+///
+/// let names = vec!["alice", "bob"];
+/// assert_eq!(format!("{}", pretty_with(&names, &Resolved(&1))), r#""bob""#);
+/// assert_eq!(format!("{}", pretty_with(&names, &Resolved(&2))), "2");
+/// ```
+pub struct Resolved<'a, Id: ?Sized>(pub &'a Id);
+
+impl<Ctx: Resolve<Id>, Id: DebugWith<Ctx> + ?Sized> DebugWith<Ctx> for Resolved<'_, Id> {
+    fn fmt(&self, with: &Ctx, f: Formatter<'_>) {
+        let target = with.resolve(self.0);
+        match target.and_then(|target| Resolving::enter(target).map(|guard| (target, guard))) {
+            Some((target, _guard)) => target.fmt(with, f),
+            None => self.0.fmt(with, f),
+        }
+    }
+}
+
+thread_local! {
+    /// Addresses of the resolved values currently being formatted
+    static RESOLVING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks a resolved value as being formatted until dropped
+struct Resolving;
+
+impl Resolving {
+    /// Returns `None` if the value is already being formatted further up
+    fn enter<T: ?Sized>(target: &T) -> Option<Self> {
+        let addr = std::ptr::from_ref(target).cast::<()>() as usize;
+        RESOLVING.with_borrow_mut(|resolving| {
+            if resolving.contains(&addr) {
+                return None;
+            }
+            resolving.push(addr);
+            Some(Resolving)
+        })
+    }
+}
+
+impl Drop for Resolving {
+    fn drop(&mut self) {
+        RESOLVING.with_borrow_mut(Vec::pop);
+    }
+}
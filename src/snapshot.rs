@@ -0,0 +1,53 @@
+use crate::{pretty::pretty_string, Config, DebugPls, Pointers};
+
+/// Implementation detail for the `assert_snapshot_pls!` macro
+///
+/// Formats the value without colors, with sorted `HashMap`/`HashSet` entries and
+/// redacted pointer addresses so that snapshots are stable between runs.
+pub fn snapshot(value: &dyn DebugPls) -> String {
+    let config = Config::new()
+        .sort_unordered(true)
+        .pointers(Pointers::Redact);
+    pretty_string(&config.process(value), config.max_width())
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "insta")))]
+#[macro_export]
+/// Asserts a [`DebugPls`] value against an [`insta`] snapshot.
+///
+/// The value is stored in its uncolored [`pretty`] form.
+/// To keep snapshots from churning between runs, the entries of unordered
+/// collections like `HashMap` and `HashSet` are sorted, and pointer addresses
+/// are replaced with placeholders (`0x1_ptr`, `0x2_ptr`, ...) numbered by first appearance.
+///
+/// Like [`insta::assert_snapshot!`], the snapshot name is optional.
+///
+/// ```rust,no_run
+/// # use dbg_pls::assert_snapshot_pls;
+/// use std::collections::HashMap;
+///
+/// let map = HashMap::from([("hello", 1), ("world", 2)]);
+/// assert_snapshot_pls!(map);
+/// assert_snapshot_pls!("named_snapshot", map);
+/// ```
+///
+/// [`DebugPls`]: crate::DebugPls
+/// [`pretty`]: crate::pretty
+/// [`insta`]: https://docs.rs/insta
+/// [`insta::assert_snapshot!`]: https://docs.rs/insta/*/insta/macro.assert_snapshot.html
+macro_rules! assert_snapshot_pls {
+    ($value:expr $(,)?) => {
+        $crate::__private::insta::assert_snapshot!(
+            ::std::option::Option::None::<&str>,
+            $crate::__private::snapshot(&$value),
+            ::std::stringify!($value)
+        )
+    };
+    ($name:expr, $value:expr $(,)?) => {
+        $crate::__private::insta::assert_snapshot!(
+            $name,
+            $crate::__private::snapshot(&$value),
+            ::std::stringify!($value)
+        )
+    };
+}
@@ -0,0 +1,83 @@
+//! Markers that the builders attach to their output, so that it can be highlighted
+//! by which builder wrote it rather than by how it looks.
+//!
+//! Tags are outer attributes such as `#[dbg_pls::variant]`.
+//! The printer reads them but never prints them.
+
+use proc_macro2::Span;
+use syn::visit_mut::{self, VisitMut};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Tag {
+    /// A struct from `DebugStruct`, with a type name and field names
+    Struct,
+    /// The name of a tuple struct or unit value, from `DebugTupleStruct` or `debug_ident`
+    Variant,
+    /// An entry from `DebugMap`, whose left side is the key
+    MapEntry,
+}
+
+impl Tag {
+    fn name(self) -> &'static str {
+        match self {
+            Tag::Struct => "struct",
+            Tag::Variant => "variant",
+            Tag::MapEntry => "map_entry",
+        }
+    }
+
+    pub(crate) fn attr(self) -> syn::Attribute {
+        let mut path = syn::Path::from(syn::Ident::new("dbg_pls", Span::call_site()));
+        path.segments
+            .push(syn::Ident::new(self.name(), Span::call_site()).into());
+        syn::Attribute {
+            pound_token: syn::token::Pound::default(),
+            style: syn::AttrStyle::Outer,
+            bracket_token: syn::token::Bracket::default(),
+            meta: syn::Meta::Path(path),
+        }
+    }
+
+    /// The tag that the attribute represents, if it is one
+    pub(crate) fn of(attr: &syn::Attribute) -> Option<Self> {
+        let syn::Meta::Path(path) = &attr.meta else {
+            return None;
+        };
+        let mut segments = path.segments.iter().map(|segment| &segment.ident);
+        match (segments.next(), segments.next(), segments.next()) {
+            (Some(krate), Some(name), None) if krate == "dbg_pls" => {
+                [Tag::Struct, Tag::Variant, Tag::MapEntry]
+                    .into_iter()
+                    .find(|tag| name == tag.name())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the attributes include this tag
+    #[cfg_attr(not(feature = "pretty"), allow(dead_code))]
+    pub(crate) fn is_on(self, attrs: &[syn::Attribute]) -> bool {
+        attrs.iter().any(|attr| Tag::of(attr) == Some(self))
+    }
+}
+
+/// A copy of the expression without any tags, as the user would have written it
+pub(crate) fn untagged(expr: &syn::Expr) -> syn::Expr {
+    struct Untag;
+
+    impl VisitMut for Untag {
+        fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+            if let syn::Expr::Assign(syn::ExprAssign { attrs, .. })
+            | syn::Expr::Path(syn::ExprPath { attrs, .. })
+            | syn::Expr::Struct(syn::ExprStruct { attrs, .. }) = expr
+            {
+                attrs.retain(|attr| Tag::of(attr).is_none());
+            }
+            visit_mut::visit_expr_mut(self, expr);
+        }
+    }
+
+    let mut expr = expr.clone();
+    Untag.visit_expr_mut(&mut expr);
+    expr
+}
@@ -0,0 +1,104 @@
+//! Integration with [`tracing`](https://docs.rs/tracing)
+
+use std::fmt;
+
+use ::tracing::field::{display, DisplayValue};
+
+use crate::DebugPls;
+
+/// Wraps a [`DebugPls`] type into a [`tracing::Value`](::tracing::Value) for use as a field
+/// in `tracing` events and spans, which is recorded using its pretty printed form.
+///
+/// Like the `log_pretty!` macros, the value is only formatted if the subscriber records it,
+/// and the `DBG_PLS_*` environment variables are applied. It is wrapped to the width set by
+/// [`Config::width`](crate::Config::width) or `DBG_PLS_WIDTH`, rather than to the terminal.
+///
+/// ```rust
+/// use dbg_pls::{tracing::pretty_value, DebugPls};
+///
+/// #[derive(DebugPls)]
+/// struct State {
+///     count: usize,
+/// }
+///
+/// let state = State { count: 5 };
+/// tracing::info!(state = pretty_value(&state), "updated");
+/// ```
+///
+/// # Structured values
+///
+/// The value is recorded as a single formatted field, not as a structure that subscribers
+/// could inspect. The [`tracing::Value`](::tracing::Value) trait is sealed, so only the types
+/// that `tracing` provides can be recorded, and the only structured one among them is
+/// `valuable::Valuable`, which is still behind the unstable `tracing_unstable` cfg.
+pub fn pretty_value<T: DebugPls>(value: &T) -> DisplayValue<impl fmt::Display + '_> {
+    display(Pretty(value))
+}
+
+struct Pretty<'a, T>(&'a T);
+
+impl<T: DebugPls> fmt::Display for Pretty<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&crate::__private::log_config().pretty(self.0), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use ::tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use super::pretty_value;
+
+    /// Records the fields of every event
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Capture {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            let value = format!("{value:?}");
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_owned(), value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn record() {
+        let capture = Capture::default();
+        ::tracing::subscriber::with_default(capture.clone(), || {
+            ::tracing::info!(value = pretty_value(&Some((1, "a"))), "updated");
+        });
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            [
+                ("message".to_owned(), "updated".to_owned()),
+                ("value".to_owned(), r#"Some((1, "a"))"#.to_owned()),
+            ]
+        );
+    }
+}
@@ -206,3 +206,81 @@ fn raw_idents() {
 
     assert_pretty_snapshot!(&r#enum::r#struct { r#fn: () });
 }
+
+mod debug_resolve {
+    use dbg_pls::{pretty, pretty_with, DebugPls, Resolve, Resolved};
+
+    #[derive(DebugPls, Clone, Copy)]
+    pub struct NodeId(usize);
+
+    #[derive(DebugPls)]
+    pub enum Node {
+        Leaf(i32),
+        Branch {
+            #[dbg_pls(resolve)]
+            left: NodeId,
+            #[dbg_pls(resolve)]
+            right: NodeId,
+        },
+    }
+
+    pub struct Arena(Vec<Node>);
+
+    impl Resolve<NodeId> for Arena {
+        type Target = Node;
+        fn resolve(&self, id: &NodeId) -> Option<&Node> {
+            self.0.get(id.0)
+        }
+    }
+
+    #[derive(DebugPls)]
+    pub struct Symbol(#[dbg_pls(resolve)] usize);
+
+    #[test]
+    fn arena() {
+        let arena = Arena(vec![
+            Node::Leaf(1),
+            Node::Branch {
+                left: NodeId(0),
+                right: NodeId(5),
+            },
+        ]);
+        assert_eq!(
+            pretty_with(&arena, &arena.0[1]).to_string(),
+            "Branch {\n    left: Leaf(1),\n    right: NodeId(5),\n}",
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let arena = Arena(vec![Node::Branch {
+            left: NodeId(0),
+            right: NodeId(1),
+        }]);
+        assert_eq!(
+            pretty_with(&arena, &Resolved(&NodeId(0))).to_string(),
+            "Branch {\n    left: NodeId(0),\n    right: NodeId(1),\n}",
+        );
+    }
+
+    #[test]
+    fn no_context() {
+        let node = Node::Branch {
+            left: NodeId(0),
+            right: NodeId(1),
+        };
+        assert_eq!(
+            pretty(&node).to_string(),
+            "Branch {\n    left: NodeId(0),\n    right: NodeId(1),\n}",
+        );
+    }
+
+    #[test]
+    fn interner() {
+        let symbols = vec!["foo", "bar"];
+        assert_eq!(
+            pretty_with(&symbols, &[Symbol(1), Symbol(2)]).to_string(),
+            r#"[Symbol("bar"), Symbol(2)]"#,
+        );
+    }
+}
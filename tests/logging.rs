@@ -0,0 +1,56 @@
+use std::sync::Mutex;
+
+use dbg_pls::{debug_pretty, log_pretty, warn_pretty};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+struct Capture(Mutex<Vec<(Level, String, String)>>);
+
+impl Log for Capture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.0.lock().unwrap().push((
+                record.level(),
+                record.target().to_owned(),
+                record.args().to_string(),
+            ));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn log_macros() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let a = 2;
+    assert_eq!(warn_pretty!(a * 2), 4);
+    assert_eq!(log_pretty!(target: "app", Level::Info, a, "b"), (2, "b"));
+    // disabled by the logger
+    assert_eq!(debug_pretty!(a), 2);
+
+    let logs = LOGGER.0.lock().unwrap();
+    let logs: Vec<_> = logs
+        .iter()
+        .map(|(level, target, message)| {
+            // strip the line number
+            let (_, message) = message.split_once("] ").unwrap();
+            (*level, target.as_str(), message)
+        })
+        .collect();
+    assert_eq!(
+        logs,
+        [
+            (Level::Warn, "logging", "a * 2 => 4"),
+            (Level::Info, "app", "a => 2"),
+            (Level::Info, "app", "\"b\" => \"b\""),
+        ]
+    );
+}
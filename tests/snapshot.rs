@@ -0,0 +1,77 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use dbg_pls::{assert_snapshot_pls, DebugPls};
+
+#[derive(DebugPls)]
+pub struct Node {
+    name: &'static str,
+    parent: *const Node,
+}
+
+#[derive(DebugPls)]
+pub struct Tree {
+    name: &'static str,
+    children: BTreeMap<&'static str, BTreeSet<i32>>,
+}
+
+#[test]
+fn btree_map() {
+    assert_snapshot_pls!(Tree {
+        name: "root",
+        children: BTreeMap::from([
+            ("hello", BTreeSet::from([1, 2, 3])),
+            ("world", BTreeSet::new()),
+        ]),
+    });
+}
+
+#[test]
+fn hash_map() {
+    assert_snapshot_pls!(HashMap::from([
+        ("hello", HashSet::from([1, 2, 3, 4, 5])),
+        ("world", HashSet::from([6, 7, 8, 9])),
+        ("goodbye", HashSet::new()),
+    ]));
+}
+
+#[test]
+fn pointers() {
+    let root = Node {
+        name: "root",
+        parent: std::ptr::null(),
+    };
+    let child = Node {
+        name: "child",
+        parent: &root,
+    };
+    let sibling = Node {
+        name: "sibling",
+        parent: &root,
+    };
+    assert_snapshot_pls!("pointers", [root, child, sibling]);
+}
+
+#[derive(Debug)]
+struct ConfigError {
+    source: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("could not parse the config file")
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[test]
+fn error_chain() {
+    let err: Box<dyn std::error::Error + Send + Sync> = Box::new(ConfigError {
+        source: "forty two".parse::<i32>().unwrap_err(),
+    });
+    assert_snapshot_pls!(err);
+}